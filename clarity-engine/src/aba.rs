@@ -0,0 +1,425 @@
+use crate::sat_solver::dpll_model;
+use crate::types::{is_dependency_edge, AbaExtensions, LogicalGraph};
+use std::collections::{HashMap, HashSet};
+
+/// Maximum number of models collected per semantics before giving up on
+/// enumerating further — guards against a pathologically large assumption
+/// set turning one request into an exponential number of SAT calls, the
+/// same spirit as `sat_solver`'s `MAX_NODES` and `minimal_unsat_core`'s
+/// `MAX_CORE_CANDIDATES`.
+const MAX_EXTENSIONS: usize = 256;
+
+/// Compute the classical Dung-style argumentation semantics — admissible,
+/// complete, stable, and grounded extensions — over `graph`'s `assumption`
+/// propositions, encoding acceptance as a boolean `in_a` per assumption and
+/// solving with `sat_solver`'s DPLL.
+///
+/// The attack relation between assumptions is derived, not given directly:
+/// an `attacks`/`contradicts` relationship `u -> v` means the argument built
+/// on `u` attacks the argument built on `v`, i.e. every assumption `u`'s
+/// conclusion depends on (found by backward reachability over
+/// `supports`/`depends_on`/`assumes` edges, see `assumptions_of`) attacks
+/// every assumption `v`'s conclusion depends on in turn. `contradicts` is
+/// treated as attacking both ways; `attacks` only in the edge's direction.
+///
+/// An assumption with no attacker is defended by every set, so it belongs to
+/// every complete, stable and grounded extension; the admissible extensions
+/// are enumerated both with and without it.
+pub fn compute_extensions(graph: &LogicalGraph) -> AbaExtensions {
+    let mut assumption_ids: Vec<String> =
+        graph.get_propositions_by_type("assumption").iter().map(|p| p.id.clone()).collect();
+    assumption_ids.sort();
+
+    let attacks = build_attacks(graph, &assumption_ids);
+    let attacked_by = reverse_attacks(&assumption_ids, &attacks);
+    let var_index: HashMap<String, usize> =
+        assumption_ids.iter().enumerate().map(|(i, id)| (id.clone(), i + 1)).collect();
+
+    let conflict_free = conflict_free_clauses(&attacks, &var_index);
+
+    let mut admissible_clauses = conflict_free.clone();
+    admissible_clauses.extend(admissibility_clauses(&assumption_ids, &attacked_by, &var_index));
+    let admissible: Vec<Vec<String>> =
+        enumerate_models(&admissible_clauses, var_index.len()).iter().map(|m| ids_from_model(m, &var_index)).collect();
+
+    let complete: Vec<Vec<String>> = admissible
+        .iter()
+        .filter(|extension| {
+            let accepted: HashSet<String> = extension.iter().cloned().collect();
+            assumption_ids.iter().all(|a| !is_defended(a, &accepted, &attacked_by) || accepted.contains(a))
+        })
+        .cloned()
+        .collect();
+
+    let mut stable_clauses = conflict_free;
+    stable_clauses.extend(stability_clauses(&assumption_ids, &attacked_by, &var_index));
+    let stable: Vec<Vec<String>> =
+        enumerate_models(&stable_clauses, var_index.len()).iter().map(|m| ids_from_model(m, &var_index)).collect();
+
+    let grounded = grounded_extension(&assumption_ids, &attacks, &attacked_by);
+
+    AbaExtensions { admissible, complete, stable, grounded }
+}
+
+/// The assumptions each `attacks`/`contradicts` relationship's endpoints
+/// ultimately rest on, turned into attacks between those assumptions
+/// directly.
+fn build_attacks(graph: &LogicalGraph, assumption_ids: &[String]) -> HashMap<String, HashSet<String>> {
+    let mut attacks: HashMap<String, HashSet<String>> =
+        assumption_ids.iter().map(|a| (a.clone(), HashSet::new())).collect();
+    let mut memo: HashMap<String, HashSet<String>> = HashMap::new();
+
+    for rel in &graph.relationships {
+        if rel.rel_type != "attacks" && rel.rel_type != "contradicts" {
+            continue;
+        }
+        let from_assumptions = assumptions_of(graph, &rel.from_id, &mut memo, &mut HashSet::new());
+        let to_assumptions = assumptions_of(graph, &rel.to_id, &mut memo, &mut HashSet::new());
+        for a in &from_assumptions {
+            for b in &to_assumptions {
+                attacks.entry(a.clone()).or_default().insert(b.clone());
+                if rel.rel_type == "contradicts" {
+                    attacks.entry(b.clone()).or_default().insert(a.clone());
+                }
+            }
+        }
+    }
+    attacks
+}
+
+/// The set of `assumption` propositions `id`'s conclusion transitively
+/// depends on via dependency edges (`supports`/`depends_on`/`assumes`) —
+/// `id` itself if it's an assumption. Memoized and cycle-guarded the same
+/// way as `grounding::is_grounded`: a node still being explored when
+/// revisited contributes nothing on that path, so a result that passed
+/// through such a revisit may be missing assumptions and is only cached by
+/// the outermost call, whose backward search is complete.
+fn assumptions_of(
+    graph: &LogicalGraph,
+    id: &str,
+    memo: &mut HashMap<String, HashSet<String>>,
+    visiting: &mut HashSet<String>,
+) -> HashSet<String> {
+    if let Some(cached) = memo.get(id) {
+        return cached.clone();
+    }
+    if !visiting.insert(id.to_string()) {
+        return HashSet::new();
+    }
+
+    let mut result: HashSet<String> = HashSet::new();
+    if graph.get_proposition(id).map(|p| p.prop_type == "assumption").unwrap_or(false) {
+        result.insert(id.to_string());
+    }
+    let mut complete = true;
+    for rel in graph.get_relationships_to(id) {
+        if is_dependency_edge(&rel.rel_type) {
+            result.extend(assumptions_of(graph, &rel.from_id, memo, visiting));
+            complete &= memo.contains_key(&rel.from_id);
+        }
+    }
+
+    visiting.remove(id);
+    if complete || visiting.is_empty() {
+        memo.insert(id.to_string(), result.clone());
+    }
+    result
+}
+
+fn reverse_attacks(
+    assumption_ids: &[String],
+    attacks: &HashMap<String, HashSet<String>>,
+) -> HashMap<String, HashSet<String>> {
+    let mut attacked_by: HashMap<String, HashSet<String>> =
+        assumption_ids.iter().map(|a| (a.clone(), HashSet::new())).collect();
+    for (attacker, targets) in attacks {
+        for target in targets {
+            attacked_by.entry(target.clone()).or_default().insert(attacker.clone());
+        }
+    }
+    attacked_by
+}
+
+/// Conflict-freeness: an accepted assumption can't attack another accepted
+/// one — `(¬in_a ∨ ¬in_b)` for every `a` attacks `b`. A self-attacking
+/// assumption collapses to the unit clause `¬in_a`, since it can never be
+/// accepted without conflicting with itself.
+fn conflict_free_clauses(attacks: &HashMap<String, HashSet<String>>, var_index: &HashMap<String, usize>) -> Vec<Vec<i32>> {
+    let mut clauses = Vec::new();
+    for (a, targets) in attacks {
+        let lit_a = var_index[a] as i32;
+        for b in targets {
+            let lit_b = var_index[b] as i32;
+            if lit_a == lit_b {
+                clauses.push(vec![-lit_a]);
+            } else {
+                clauses.push(vec![-lit_a, -lit_b]);
+            }
+        }
+    }
+    clauses
+}
+
+/// Admissibility (defense): if `a` is accepted, every attacker `b` of `a`
+/// must itself be attacked by the accepted set — `(¬in_a ∨ in_c1 ∨ in_c2 ∨
+/// ...)` for each attacker `b` of `a`, where `c1, c2, ...` are `b`'s own
+/// attackers. An attacker with no attackers of its own collapses the clause
+/// to `¬in_a`: `a` can never be defended against it, so it can never be
+/// accepted.
+fn admissibility_clauses(
+    assumption_ids: &[String],
+    attacked_by: &HashMap<String, HashSet<String>>,
+    var_index: &HashMap<String, usize>,
+) -> Vec<Vec<i32>> {
+    let mut clauses = Vec::new();
+    for a in assumption_ids {
+        let Some(attackers_of_a) = attacked_by.get(a) else { continue };
+        let mut attackers: Vec<&String> = attackers_of_a.iter().collect();
+        attackers.sort();
+        for b in attackers {
+            let mut clause = vec![-(var_index[a] as i32)];
+            if let Some(defenders) = attacked_by.get(b) {
+                let mut defender_list: Vec<&String> = defenders.iter().collect();
+                defender_list.sort();
+                clause.extend(defender_list.into_iter().map(|c| var_index[c] as i32));
+            }
+            clauses.push(clause);
+        }
+    }
+    clauses
+}
+
+/// Stability: every assumption not accepted must be attacked by the
+/// accepted set — `(in_a ∨ in_b1 ∨ in_b2 ∨ ...)` for each assumption `a`,
+/// where `b1, b2, ...` are `a`'s attackers. An unattacked assumption
+/// collapses to the unit clause `in_a`: a stable extension must include it.
+fn stability_clauses(
+    assumption_ids: &[String],
+    attacked_by: &HashMap<String, HashSet<String>>,
+    var_index: &HashMap<String, usize>,
+) -> Vec<Vec<i32>> {
+    assumption_ids
+        .iter()
+        .map(|a| {
+            let mut clause = vec![var_index[a] as i32];
+            if let Some(attackers) = attacked_by.get(a) {
+                let mut attacker_list: Vec<&String> = attackers.iter().collect();
+                attacker_list.sort();
+                clause.extend(attacker_list.into_iter().map(|b| var_index[b] as i32));
+            }
+            clause
+        })
+        .collect()
+}
+
+/// Enumerate every satisfying model of `clauses` by repeatedly solving with
+/// DPLL and, on success, adding a "blocking clause" that forbids the exact
+/// assignment just found before solving again — standard All-SAT via
+/// blocking clauses. Stops at `MAX_EXTENSIONS` models or once the formula is
+/// unsatisfiable, whichever comes first.
+fn enumerate_models(base_clauses: &[Vec<i32>], num_vars: usize) -> Vec<Vec<Option<bool>>> {
+    let mut clauses = base_clauses.to_vec();
+    let mut models = Vec::new();
+
+    while models.len() < MAX_EXTENSIONS {
+        let Some(mut model) = dpll_model(&clauses, num_vars) else { break };
+        // DPLL leaves a variable no clause constrains unassigned; either value
+        // satisfies every clause, so pin it to `false` and let the blocking
+        // clause lead a later solve to the model with it `true`.
+        for value in model.iter_mut().skip(1) {
+            if value.is_none() {
+                *value = Some(false);
+            }
+        }
+        let blocking: Vec<i32> = (1..=num_vars)
+            .filter_map(|v| model.get(v).copied().flatten().map(|val| if val { -(v as i32) } else { v as i32 }))
+            .collect();
+        models.push(model);
+        if blocking.is_empty() {
+            break;
+        }
+        clauses.push(blocking);
+    }
+
+    models
+}
+
+/// Every assumption the model assigned `true` (`enumerate_models` assigns
+/// every variable, so an unassigned one only ever reads as `false`).
+fn ids_from_model(model: &[Option<bool>], var_index: &HashMap<String, usize>) -> Vec<String> {
+    let mut ids: Vec<String> = var_index
+        .iter()
+        .filter(|(_, &v)| model.get(v).copied().flatten().unwrap_or(false))
+        .map(|(id, _)| id.clone())
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Whether `a` is defended by `accepted`: every attacker of `a` is itself
+/// attacked by some member of `accepted`. Vacuously true if `a` has no
+/// attackers.
+fn is_defended(a: &str, accepted: &HashSet<String>, attacked_by: &HashMap<String, HashSet<String>>) -> bool {
+    let Some(attackers) = attacked_by.get(a) else { return true };
+    attackers
+        .iter()
+        .all(|b| attacked_by.get(b).map(|defenders| defenders.iter().any(|c| accepted.contains(c))).unwrap_or(false))
+}
+
+/// The grounded extension: the unique least fixed point of "accept every
+/// assumption defended by what's already accepted", starting from the empty
+/// set and iterating to a fixpoint — no SAT needed, since this
+/// characteristic function is monotone and has exactly one least fixpoint
+/// in any finite framework. A self-attacking assumption is excluded
+/// outright, since it can never belong to any conflict-free set regardless
+/// of what else is accepted.
+fn grounded_extension(
+    assumption_ids: &[String],
+    attacks: &HashMap<String, HashSet<String>>,
+    attacked_by: &HashMap<String, HashSet<String>>,
+) -> Vec<String> {
+    let mut accepted: HashSet<String> = HashSet::new();
+    loop {
+        let mut changed = false;
+        for a in assumption_ids {
+            if accepted.contains(a) {
+                continue;
+            }
+            if attacks.get(a).map(|s| s.contains(a)).unwrap_or(false) {
+                continue;
+            }
+            if is_defended(a, &accepted, attacked_by) {
+                accepted.insert(a.clone());
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut result: Vec<String> = accepted.into_iter().collect();
+    result.sort();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_empty_framework_has_only_empty_extensions() {
+        let graph = make_graph(vec![make_prop("C", "claim", "high")], vec![]);
+        let extensions = compute_extensions(&graph);
+        assert_eq!(extensions.admissible, vec![Vec::<String>::new()]);
+        assert_eq!(extensions.complete, vec![Vec::<String>::new()]);
+        assert_eq!(extensions.stable, vec![Vec::<String>::new()]);
+        assert!(extensions.grounded.is_empty());
+    }
+
+    #[test]
+    fn test_unattacked_assumption_is_accepted_everywhere() {
+        let graph = make_graph(vec![make_prop("A", "assumption", "medium")], vec![]);
+        let extensions = compute_extensions(&graph);
+        assert_eq!(extensions.grounded, vec!["A".to_string()]);
+        assert!(extensions.stable.contains(&vec!["A".to_string()]));
+    }
+
+    #[test]
+    fn test_self_attacking_assumption_is_never_accepted() {
+        let graph = make_graph(
+            vec![make_prop("A", "assumption", "medium")],
+            vec![make_rel("r1", "A", "A", "contradicts")],
+        );
+        let extensions = compute_extensions(&graph);
+        assert!(extensions.grounded.is_empty());
+        assert!(extensions.admissible.iter().all(|ext| !ext.contains(&"A".to_string())));
+    }
+
+    #[test]
+    fn test_mutual_attack_yields_two_stable_singletons() {
+        // A and B mutually attack (via a symmetric "contradicts" edge): the
+        // grounded extension is empty, but {A} and {B} are each admissible,
+        // complete, and stable on their own.
+        let graph = make_graph(
+            vec![make_prop("A", "assumption", "medium"), make_prop("B", "assumption", "medium")],
+            vec![make_rel("r1", "A", "B", "contradicts")],
+        );
+        let extensions = compute_extensions(&graph);
+        assert!(extensions.grounded.is_empty());
+        assert!(extensions.stable.contains(&vec!["A".to_string()]));
+        assert!(extensions.stable.contains(&vec!["B".to_string()]));
+        assert_eq!(extensions.stable.len(), 2);
+        assert!(extensions.complete.contains(&vec!["A".to_string()]));
+        assert!(extensions.complete.contains(&vec!["B".to_string()]));
+        assert!(extensions.complete.contains(&Vec::new()));
+    }
+
+    #[test]
+    fn test_odd_attack_cycle_has_no_stable_extension() {
+        // A -> B -> C -> A, each an asymmetric "attacks" edge: every pair
+        // conflicts, so only singletons and the empty set are conflict-free,
+        // and every one of those leaves some assumption undefended against
+        // its sole attacker — the classic case with no stable extension.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "assumption", "medium"),
+                make_prop("B", "assumption", "medium"),
+                make_prop("C", "assumption", "medium"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "attacks"),
+                make_rel("r2", "B", "C", "attacks"),
+                make_rel("r3", "C", "A", "attacks"),
+            ],
+        );
+        let extensions = compute_extensions(&graph);
+        assert!(extensions.stable.is_empty());
+        assert!(extensions.grounded.is_empty());
+        assert!(extensions.admissible.contains(&Vec::new()));
+    }
+
+    #[test]
+    fn test_attack_reaches_through_a_dependency_chain() {
+        // D attacks claim C, and C depends on (is supported by) assumption
+        // A — so the argument for C rests on A, and attacking C's argument
+        // attacks A. D itself has no attacker, so it's unconditionally
+        // accepted, which leaves A permanently undefended.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "assumption", "medium"),
+                make_prop("D", "assumption", "medium"),
+                make_prop("C", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "C", "supports"),
+                make_rel("r2", "D", "C", "attacks"),
+            ],
+        );
+        let extensions = compute_extensions(&graph);
+        assert_eq!(extensions.grounded, vec!["D".to_string()]);
+        assert!(extensions.admissible.iter().all(|ext| !ext.contains(&"A".to_string())));
+    }
+
+    #[test]
+    fn test_defended_assumption_is_grounded() {
+        // B attacks A, but C attacks B in turn — A is defended by {C}.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "assumption", "medium"),
+                make_prop("B", "assumption", "medium"),
+                make_prop("C", "assumption", "medium"),
+            ],
+            vec![
+                make_rel("r1", "B", "A", "attacks"),
+                make_rel("r2", "C", "B", "attacks"),
+            ],
+        );
+        let extensions = compute_extensions(&graph);
+        let grounded: HashSet<String> = extensions.grounded.into_iter().collect();
+        assert!(grounded.contains("A"));
+        assert!(grounded.contains("C"));
+        assert!(!grounded.contains("B"));
+    }
+}
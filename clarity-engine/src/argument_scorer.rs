@@ -1,28 +1,70 @@
-use std::collections::HashMap;
-use crate::types::{ArgumentScore, Contradiction, LogicalGraph};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use crate::types::{ArgumentScore, Contradiction, Equivocation, EvidenceStrength, LogicalGraph, Proposition, ReasoningCycle};
+
+/// How many of a proposition's strongest, distinct support paths to surface
+/// on its `ArgumentScore` — enough for a reviewer to judge independence of
+/// evidence without dumping every path the solver considered.
+const STRONGEST_PATHS_PER_PROPOSITION: usize = 3;
 
 /// Score each proposition on a 0.0 to 1.0 scale.
 /// Higher = better supported, lower = more vulnerable.
 ///
 /// Score formula:
-///   base = evidence_paths / (evidence_paths + 1)
-///   penalty = contradiction_count * 0.3 + vulnerable_assumptions * 0.2
-///   bonus = centrality * 0.1 (being well-connected is a mild positive)
+///   direct_base = evidence_paths / (evidence_paths + 1)
+///   base = direct_base ∪ propagated_strength (probabilistic OR, so either
+///          signal alone is enough to establish grounding, and they reinforce)
+///   penalty = contradiction_count * 0.3 + vulnerable_assumptions * 0.2 + is_circular * 0.2
+///   bonus = centrality * 0.1 + influence * 0.1 (centrality and PageRank are mild positives)
 ///   score = clamp(base - penalty + bonus, 0.0, 1.0)
+///
+/// `influence` is an optional PageRank-style authority score (see
+/// `graph::centrality::pagerank`) — pass an empty map to omit the term
+/// entirely. Betweenness centrality rewards "many paths pass through here";
+/// influence additionally rewards "backed by evidence that is itself
+/// well-supported," which a flat supports-count cannot distinguish.
+///
+/// `evidence_strength` is the per-proposition result of
+/// `propagate_evidence_strength` — pass an empty map to fall back to the
+/// flat direct-supports base.
+///
+/// `equivocations` flags sources that argue both sides of a contradiction
+/// (see `equivocation_detector`). A `supports` edge whose author is one of
+/// these sources is excluded from `evidence_paths`, since a witness that
+/// backs both sides of a dispute provides no net support.
 pub fn score_arguments(
     graph: &LogicalGraph,
     contradictions: &[Contradiction],
     centrality: &HashMap<String, f64>,
+    circular_reasoning: &[ReasoningCycle],
+    influence: &HashMap<String, f64>,
+    evidence_strength: &HashMap<String, EvidenceStrength>,
+    equivocations: &[Equivocation],
 ) -> Vec<ArgumentScore> {
+    let circular_nodes: HashSet<&str> = circular_reasoning
+        .iter()
+        .flat_map(|c| c.proposition_ids.iter().map(|id| id.as_str()))
+        .collect();
+    let equivocating_sources: HashSet<&str> =
+        equivocations.iter().map(|e| e.source_id.as_str()).collect();
+
     graph
         .propositions
         .iter()
         .map(|prop| {
-            // Count incoming "supports" edges
+            // Count incoming "supports" edges, excluding any whose author is
+            // an equivocating source.
             let evidence_paths = graph
                 .get_relationships_to(&prop.id)
                 .iter()
                 .filter(|r| r.rel_type == "supports")
+                .filter(|r| {
+                    graph
+                        .get_proposition(&r.from_id)
+                        .and_then(|p| p.source.as_deref())
+                        .map(|s| !equivocating_sources.contains(s))
+                        .unwrap_or(true)
+                })
                 .count() as u32;
 
             // Count contradictions involving this proposition
@@ -35,13 +77,26 @@ pub fn score_arguments(
             // An assumption is "vulnerable" if it's load-bearing AND has zero supporting evidence
             let vulnerable_assumptions = count_vulnerable_assumptions(graph, &prop.id);
 
+            // A proposition inside a circular-reasoning cluster ultimately
+            // supports itself — it has no grounded evidence, same flavor of
+            // vulnerability as a load-bearing assumption with no backing.
+            let is_circular = circular_nodes.contains(prop.id.as_str());
+
             // Compute score
-            let base = evidence_paths as f64 / (evidence_paths as f64 + 1.0);
-            let penalty =
-                contradiction_count as f64 * 0.3 + vulnerable_assumptions as f64 * 0.2;
+            let direct_base = evidence_paths as f64 / (evidence_paths as f64 + 1.0);
+            let propagated_strength = evidence_strength.get(&prop.id).map(|s| s.strength).unwrap_or(0.0);
+            let base = direct_base + propagated_strength - direct_base * propagated_strength;
+
+            let penalty = contradiction_count as f64 * 0.3
+                + vulnerable_assumptions as f64 * 0.2
+                + if is_circular { 0.2 } else { 0.0 };
             let centrality_bonus = centrality.get(&prop.id).copied().unwrap_or(0.0) * 0.1;
+            let influence_bonus = influence.get(&prop.id).copied().unwrap_or(0.0) * 0.1;
+
+            let score = (base - penalty + centrality_bonus + influence_bonus).clamp(0.0, 1.0);
 
-            let score = (base - penalty + centrality_bonus).clamp(0.0, 1.0);
+            let strongest_paths =
+                k_strongest_evidence_paths(graph, &prop.id, STRONGEST_PATHS_PER_PROPOSITION);
 
             ArgumentScore {
                 proposition_id: prop.id.clone(),
@@ -49,11 +104,379 @@ pub fn score_arguments(
                 evidence_paths,
                 contradiction_count,
                 vulnerable_assumptions,
+                strongest_paths,
             }
         })
         .collect()
 }
 
+/// Propagate confidence-weighted support strength from grounded evidence to
+/// every proposition, via a best-path (max-product) relaxation over
+/// `supports` edges — the multiplicative analogue of Dijkstra's shortest
+/// path, since "distance" here is how much support strength survives a
+/// chain of hops rather than how much cost accumulates.
+///
+/// A grounded evidence node (type `evidence` with no incoming dependency
+/// edge) starts at strength 1.0. Each `supports` edge carries a weight
+/// derived from its source's confidence and type, so strength decays
+/// geometrically with distance — evidence two or three hops away still
+/// contributes, just more weakly. A claim reachable only via an ungrounded,
+/// load-bearing assumption inherits that assumption's weakness because the
+/// assumption's outgoing edge weight is itself discounted.
+///
+/// Returns, per proposition, the strongest arriving strength and the path
+/// (grounded evidence → ... → this proposition) that achieved it, so the UI
+/// can show *why* a claim is well-grounded.
+pub fn propagate_evidence_strength(graph: &LogicalGraph) -> HashMap<String, EvidenceStrength> {
+    let mut best: HashMap<String, f64> = HashMap::new();
+    let mut predecessor: HashMap<String, String> = HashMap::new();
+    let mut heap: BinaryHeap<HeapItem> = BinaryHeap::new();
+
+    for prop in &graph.propositions {
+        let is_grounded_root = prop.prop_type == "evidence"
+            && graph
+                .get_relationships_to(&prop.id)
+                .iter()
+                .filter(|r| crate::types::is_dependency_edge(&r.rel_type))
+                .count()
+                == 0;
+        if is_grounded_root {
+            best.insert(prop.id.clone(), 1.0);
+            heap.push(HeapItem { id: prop.id.clone(), strength: 1.0 });
+        }
+    }
+
+    while let Some(HeapItem { id, strength }) = heap.pop() {
+        if strength < best.get(&id).copied().unwrap_or(0.0) {
+            continue; // stale heap entry superseded by a stronger path
+        }
+        let Some(source) = graph.get_proposition(&id) else { continue };
+        let weight = support_edge_weight(graph, source);
+
+        for rel in graph.get_relationships_from(&id) {
+            if rel.rel_type != "supports" {
+                continue;
+            }
+            let candidate = strength * weight;
+            if candidate > best.get(&rel.to_id).copied().unwrap_or(0.0) {
+                best.insert(rel.to_id.clone(), candidate);
+                predecessor.insert(rel.to_id.clone(), id.clone());
+                heap.push(HeapItem { id: rel.to_id.clone(), strength: candidate });
+            }
+        }
+    }
+
+    graph
+        .propositions
+        .iter()
+        .map(|prop| {
+            let strength = best.get(&prop.id).copied().unwrap_or(0.0);
+            let mut path = Vec::new();
+            if strength > 0.0 {
+                let mut cur = prop.id.clone();
+                path.push(cur.clone());
+                while let Some(prev) = predecessor.get(&cur) {
+                    path.push(prev.clone());
+                    cur = prev.clone();
+                }
+                path.reverse();
+            }
+            (prop.id.clone(), EvidenceStrength { strength, path })
+        })
+        .collect()
+}
+
+/// Weight of a `supports` edge leaving `source`: how much of its strength
+/// survives the hop. Derived from the source's confidence and proposition
+/// type, with an extra discount when the source is itself an ungrounded,
+/// load-bearing assumption — so claims resting on a vulnerable assumption
+/// inherit its weakness instead of inheriting full strength.
+fn support_edge_weight(graph: &LogicalGraph, source: &Proposition) -> f64 {
+    let confidence_weight = match source.confidence.as_str() {
+        "high" => 0.95,
+        "medium" => 0.7,
+        "low" => 0.4,
+        "unstated_as_absolute" => 0.2,
+        _ => 0.5,
+    };
+    let type_factor = match source.prop_type.as_str() {
+        "evidence" => 1.0,
+        "claim" => 0.9,
+        "assumption" => 0.6,
+        _ => 0.8,
+    };
+
+    let mut weight = confidence_weight * type_factor;
+
+    if source.prop_type == "assumption" {
+        let is_grounded = graph
+            .get_relationships_to(&source.id)
+            .iter()
+            .any(|r| r.rel_type == "supports");
+        if !is_grounded && source.is_load_bearing {
+            weight *= 0.3;
+        }
+    }
+
+    weight
+}
+
+/// Find up to `k` strongest, distinct support paths reaching `target_id`,
+/// using Yen's k-shortest-paths approach over the weighted support graph
+/// from `propagate_evidence_strength` (here "shortest" means highest
+/// strength, since strength shrinks with each hop the way distance grows).
+///
+/// The first path is the single best path found by `best_path_to`. Each
+/// subsequent path is built by, for every spur node along the previous best
+/// path, temporarily removing the edges already used by prior paths sharing
+/// that same root prefix, searching for the best spur path from the spur
+/// node to the target, splicing it onto the root prefix, and queuing the
+/// result. The strongest non-duplicate candidate becomes the next path.
+///
+/// Lets reviewers see whether a claim rests on genuinely independent lines
+/// of evidence or repeatedly on the same load-bearing node.
+pub fn k_strongest_evidence_paths(
+    graph: &LogicalGraph,
+    target_id: &str,
+    k: usize,
+) -> Vec<crate::types::EvidencePath> {
+    let mut found: Vec<(f64, Vec<String>)> = Vec::new();
+    let empty_edges = HashSet::new();
+    let empty_nodes = HashSet::new();
+
+    match best_path_to(graph, target_id, &empty_edges, &empty_nodes) {
+        Some(first) => found.push(first),
+        None => return Vec::new(),
+    }
+
+    let mut candidates: BinaryHeap<PathCandidate> = BinaryHeap::new();
+    let mut queued: HashSet<Vec<String>> = HashSet::new();
+
+    while found.len() < k {
+        let prev_path = found.last().unwrap().1.clone();
+
+        // Prefix strength at each index of prev_path (path[0] is always a
+        // grounded-evidence root starting at strength 1.0).
+        let mut prefix_strength = vec![1.0_f64];
+        for i in 1..prev_path.len() {
+            let source = graph.get_proposition(&prev_path[i - 1]);
+            let weight = source.map(|s| support_edge_weight(graph, s)).unwrap_or(0.0);
+            prefix_strength.push(prefix_strength[i - 1] * weight);
+        }
+
+        for i in 0..prev_path.len().saturating_sub(1) {
+            let spur_node = prev_path[i].clone();
+            let root_path = &prev_path[0..=i];
+
+            let mut excluded_edges: HashSet<(String, String)> = HashSet::new();
+            for (_, path) in &found {
+                if path.len() > i + 1 && path[0..=i] == *root_path {
+                    excluded_edges.insert((path[i].clone(), path[i + 1].clone()));
+                }
+            }
+            let excluded_nodes: HashSet<String> = root_path[..i].iter().cloned().collect();
+
+            // At i == 0 the "root path" is just the spur node itself, so
+            // there's no committed prefix yet — re-search from any grounded
+            // root (excluding the edge(s) already used) rather than only
+            // continuing from this one root, or a second independent root
+            // (e.g. another direct-support evidence node) can never be found
+            // once this root's sole outgoing edge is excluded.
+            let spur_result = if i == 0 {
+                best_path_to(graph, target_id, &excluded_edges, &excluded_nodes)
+            } else {
+                best_path_from(
+                    graph,
+                    &spur_node,
+                    prefix_strength[i],
+                    target_id,
+                    &excluded_edges,
+                    &excluded_nodes,
+                )
+            };
+
+            if let Some((spur_strength, spur_path)) = spur_result {
+                let mut total_path = root_path[..i].to_vec();
+                total_path.extend(spur_path);
+
+                if !queued.contains(&total_path) && !found.iter().any(|(_, p)| *p == total_path) {
+                    queued.insert(total_path.clone());
+                    candidates.push(PathCandidate { strength: spur_strength, path: total_path });
+                }
+            }
+        }
+
+        match candidates.pop() {
+            Some(PathCandidate { strength, path }) => found.push((strength, path)),
+            None => break, // fewer than k distinct paths exist
+        }
+    }
+
+    found
+        .into_iter()
+        .map(|(strength, path)| crate::types::EvidencePath { strength, path })
+        .collect()
+}
+
+/// Best (highest-strength) path to `target_id` from any grounded-evidence
+/// root, skipping `excluded_edges` and `excluded_nodes`.
+fn best_path_to(
+    graph: &LogicalGraph,
+    target_id: &str,
+    excluded_edges: &HashSet<(String, String)>,
+    excluded_nodes: &HashSet<String>,
+) -> Option<(f64, Vec<String>)> {
+    let mut best: HashMap<String, f64> = HashMap::new();
+    let mut heap: BinaryHeap<HeapItem> = BinaryHeap::new();
+
+    for prop in &graph.propositions {
+        if excluded_nodes.contains(&prop.id) {
+            continue;
+        }
+        let is_grounded_root = prop.prop_type == "evidence"
+            && graph
+                .get_relationships_to(&prop.id)
+                .iter()
+                .filter(|r| crate::types::is_dependency_edge(&r.rel_type))
+                .count()
+                == 0;
+        if is_grounded_root {
+            best.insert(prop.id.clone(), 1.0);
+            heap.push(HeapItem { id: prop.id.clone(), strength: 1.0 });
+        }
+    }
+
+    let (best, predecessor) = relax_from_heap(graph, best, heap, excluded_edges, excluded_nodes);
+    reconstruct_path(target_id, best, &predecessor)
+}
+
+/// Best path from a specific `start_id` (already carrying `start_strength`)
+/// to `target_id`, skipping `excluded_edges` and `excluded_nodes`.
+fn best_path_from(
+    graph: &LogicalGraph,
+    start_id: &str,
+    start_strength: f64,
+    target_id: &str,
+    excluded_edges: &HashSet<(String, String)>,
+    excluded_nodes: &HashSet<String>,
+) -> Option<(f64, Vec<String>)> {
+    let mut best: HashMap<String, f64> = HashMap::new();
+    let mut heap: BinaryHeap<HeapItem> = BinaryHeap::new();
+    best.insert(start_id.to_string(), start_strength);
+    heap.push(HeapItem { id: start_id.to_string(), strength: start_strength });
+
+    let (best, predecessor) = relax_from_heap(graph, best, heap, excluded_edges, excluded_nodes);
+    reconstruct_path(target_id, best, &predecessor)
+}
+
+/// Shared max-product relaxation loop: drains `heap`, updating `best` and
+/// returning the final strength map plus a predecessor map for path
+/// reconstruction.
+fn relax_from_heap(
+    graph: &LogicalGraph,
+    mut best: HashMap<String, f64>,
+    mut heap: BinaryHeap<HeapItem>,
+    excluded_edges: &HashSet<(String, String)>,
+    excluded_nodes: &HashSet<String>,
+) -> (HashMap<String, f64>, HashMap<String, String>) {
+    let mut predecessor: HashMap<String, String> = HashMap::new();
+
+    while let Some(HeapItem { id, strength }) = heap.pop() {
+        if strength < best.get(&id).copied().unwrap_or(0.0) {
+            continue; // stale heap entry superseded by a stronger path
+        }
+        let Some(source) = graph.get_proposition(&id) else { continue };
+        let weight = support_edge_weight(graph, source);
+
+        for rel in graph.get_relationships_from(&id) {
+            if rel.rel_type != "supports" {
+                continue;
+            }
+            if excluded_nodes.contains(&rel.to_id) {
+                continue;
+            }
+            if excluded_edges.contains(&(id.clone(), rel.to_id.clone())) {
+                continue;
+            }
+            let candidate = strength * weight;
+            if candidate > best.get(&rel.to_id).copied().unwrap_or(0.0) {
+                best.insert(rel.to_id.clone(), candidate);
+                predecessor.insert(rel.to_id.clone(), id.clone());
+                heap.push(HeapItem { id: rel.to_id.clone(), strength: candidate });
+            }
+        }
+    }
+
+    (best, predecessor)
+}
+
+fn reconstruct_path(
+    target_id: &str,
+    best: HashMap<String, f64>,
+    predecessor: &HashMap<String, String>,
+) -> Option<(f64, Vec<String>)> {
+    let strength = *best.get(target_id)?;
+    if strength <= 0.0 {
+        return None;
+    }
+    let mut path = vec![target_id.to_string()];
+    let mut cur = target_id.to_string();
+    while let Some(prev) = predecessor.get(&cur) {
+        path.push(prev.clone());
+        cur = prev.clone();
+    }
+    path.reverse();
+    Some((strength, path))
+}
+
+/// Candidate path in Yen's priority queue, ordered by strength so the
+/// strongest pending candidate is always popped next.
+struct PathCandidate {
+    strength: f64,
+    path: Vec<String>,
+}
+impl PartialEq for PathCandidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.strength == other.strength
+    }
+}
+impl Eq for PathCandidate {}
+impl PartialOrd for PathCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for PathCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength.total_cmp(&other.strength)
+    }
+}
+
+/// Max-strength entry in the propagation priority queue. Ordered by
+/// `strength` so `BinaryHeap` (a max-heap) always pops the currently
+/// strongest frontier node next.
+struct HeapItem {
+    id: String,
+    strength: f64,
+}
+
+impl PartialEq for HeapItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.strength == other.strength
+    }
+}
+impl Eq for HeapItem {}
+impl PartialOrd for HeapItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for HeapItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength.total_cmp(&other.strength)
+    }
+}
+
 /// Count how many vulnerable assumptions a proposition depends on.
 /// Walks the "depends_on" and "assumes" edges to find assumptions
 /// that are load-bearing but have zero supporting evidence.
@@ -121,7 +544,7 @@ mod tests {
                 make_rel("r3", "E3", "C1", "supports"),
             ],
         );
-        let scores = score_arguments(&graph, &[], &HashMap::new());
+        let scores = score_arguments(&graph, &[], &HashMap::new(), &[], &HashMap::new(), &HashMap::new(), &[]);
         let claim_score = scores.iter().find(|s| s.proposition_id == "C1").unwrap();
 
         assert!(claim_score.score > 0.6, "Well-supported claim should score > 0.6, got {}", claim_score.score);
@@ -146,9 +569,10 @@ mod tests {
             severity: "critical".to_string(),
             formal_proof: "test".to_string(),
             human_explanation: "test".to_string(),
+            proof: Vec::new(),
         };
 
-        let scores = score_arguments(&graph, &[contradiction], &HashMap::new());
+        let scores = score_arguments(&graph, &[contradiction], &HashMap::new(), &[], &HashMap::new(), &HashMap::new(), &[]);
         let claim_score = scores.iter().find(|s| s.proposition_id == "C1").unwrap();
 
         assert!(claim_score.score < 0.5, "Contradicted claim should score < 0.5, got {}", claim_score.score);
@@ -159,7 +583,7 @@ mod tests {
     fn test_unsupported_claim_scores_low() {
         let claim = make_prop("C1", "claim", "high");
         let graph = make_graph(vec![claim], vec![]);
-        let scores = score_arguments(&graph, &[], &HashMap::new());
+        let scores = score_arguments(&graph, &[], &HashMap::new(), &[], &HashMap::new(), &HashMap::new(), &[]);
         let claim_score = scores.iter().find(|s| s.proposition_id == "C1").unwrap();
 
         assert!(claim_score.score < 0.1, "Unsupported claim should score near 0, got {}", claim_score.score);
@@ -178,7 +602,7 @@ mod tests {
             vec![make_rel("r1", "C1", "A1", "depends_on")],
         );
 
-        let scores = score_arguments(&graph, &[], &HashMap::new());
+        let scores = score_arguments(&graph, &[], &HashMap::new(), &[], &HashMap::new(), &HashMap::new(), &[]);
         let claim_score = scores.iter().find(|s| s.proposition_id == "C1").unwrap();
 
         assert_eq!(claim_score.vulnerable_assumptions, 1);
@@ -195,7 +619,133 @@ mod tests {
             ],
             vec![],
         );
-        let scores = score_arguments(&graph, &[], &HashMap::new());
+        let scores = score_arguments(&graph, &[], &HashMap::new(), &[], &HashMap::new(), &HashMap::new(), &[]);
         assert_eq!(scores.len(), 3, "Every proposition should get a score");
     }
+
+    #[test]
+    fn test_circular_reasoning_penalty() {
+        let claim = make_prop("A", "claim", "high");
+        let evidence = make_prop("B", "evidence", "high");
+
+        let graph = make_graph(
+            vec![claim, evidence],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+            ],
+        );
+        let cycle = ReasoningCycle {
+            proposition_ids: vec!["A".to_string(), "B".to_string()],
+        };
+
+        let scores = score_arguments(&graph, &[], &HashMap::new(), &[cycle], &HashMap::new(), &HashMap::new(), &[]);
+        let a_score = scores.iter().find(|s| s.proposition_id == "A").unwrap();
+
+        assert!(a_score.score < 0.5, "Self-supporting claim in a cycle should be penalized");
+    }
+
+    #[test]
+    fn test_influence_bonus_raises_score() {
+        let claim = make_prop("C1", "claim", "high");
+        let graph = make_graph(vec![claim], vec![]);
+
+        let no_influence = score_arguments(&graph, &[], &HashMap::new(), &[], &HashMap::new(), &HashMap::new(), &[]);
+        let with_influence = score_arguments(
+            &graph,
+            &[],
+            &HashMap::new(),
+            &[],
+            &HashMap::from([("C1".to_string(), 0.8)]),
+            &HashMap::new(),
+            &[],
+        );
+
+        let base_score = no_influence[0].score;
+        let boosted_score = with_influence[0].score;
+        assert!(boosted_score > base_score, "High influence should raise the score");
+    }
+
+    #[test]
+    fn test_propagate_evidence_strength_direct_support() {
+        let claim = make_prop("C1", "claim", "high");
+        let evidence = make_prop("E1", "evidence", "high");
+
+        let graph = make_graph(
+            vec![claim, evidence],
+            vec![make_rel("r1", "E1", "C1", "supports")],
+        );
+        let strengths = propagate_evidence_strength(&graph);
+
+        assert!(strengths["E1"].strength > 0.9, "Grounded evidence root should be near full strength");
+        assert!(strengths["C1"].strength > 0.0, "Directly supported claim should have positive strength");
+        assert_eq!(strengths["C1"].path, vec!["E1".to_string(), "C1".to_string()]);
+    }
+
+    #[test]
+    fn test_propagate_evidence_strength_decays_with_hops() {
+        // E1 → M1 → C1: strength at C1 should be strictly less than at M1.
+        let graph = make_graph(
+            vec![
+                make_prop("E1", "evidence", "high"),
+                make_prop("M1", "claim", "high"),
+                make_prop("C1", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "E1", "M1", "supports"),
+                make_rel("r2", "M1", "C1", "supports"),
+            ],
+        );
+        let strengths = propagate_evidence_strength(&graph);
+        assert!(strengths["C1"].strength < strengths["M1"].strength, "Strength should decay geometrically with distance");
+    }
+
+    #[test]
+    fn test_propagate_evidence_strength_unsupported_node_is_zero() {
+        let graph = make_graph(vec![make_prop("C1", "claim", "high")], vec![]);
+        let strengths = propagate_evidence_strength(&graph);
+        assert_eq!(strengths["C1"].strength, 0.0);
+        assert!(strengths["C1"].path.is_empty());
+    }
+
+    #[test]
+    fn test_k_strongest_paths_two_independent_routes() {
+        // E1 → C1 and E2 → C1 are two independent direct paths.
+        let graph = make_graph(
+            vec![
+                make_prop("E1", "evidence", "high"),
+                make_prop("E2", "evidence", "medium"),
+                make_prop("C1", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "E1", "C1", "supports"),
+                make_rel("r2", "E2", "C1", "supports"),
+            ],
+        );
+        let paths = k_strongest_evidence_paths(&graph, "C1", 2);
+        assert_eq!(paths.len(), 2);
+        // Highest-confidence evidence should produce the strongest path first.
+        assert_eq!(paths[0].path, vec!["E1".to_string(), "C1".to_string()]);
+        assert!(paths[0].strength >= paths[1].strength);
+    }
+
+    #[test]
+    fn test_k_strongest_paths_caps_at_available_routes() {
+        let graph = make_graph(
+            vec![
+                make_prop("E1", "evidence", "high"),
+                make_prop("C1", "claim", "high"),
+            ],
+            vec![make_rel("r1", "E1", "C1", "supports")],
+        );
+        let paths = k_strongest_evidence_paths(&graph, "C1", 5);
+        assert_eq!(paths.len(), 1, "Only one path exists, even though k=5 was requested");
+    }
+
+    #[test]
+    fn test_k_strongest_paths_no_evidence_returns_empty() {
+        let graph = make_graph(vec![make_prop("C1", "claim", "high")], vec![]);
+        let paths = k_strongest_evidence_paths(&graph, "C1", 3);
+        assert!(paths.is_empty());
+    }
 }
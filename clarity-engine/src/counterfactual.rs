@@ -0,0 +1,199 @@
+use crate::sat_solver::detect_contradictions;
+use crate::types::{Contradiction, LogicalGraph, RetractionSuggestion};
+use std::collections::HashSet;
+
+/// For each contradiction Clarity finds, search for a minimal subset of
+/// its `"assumption"`-typed propositions — the only kind treated as
+/// negotiable; found greedily, so not necessarily the smallest one — whose
+/// retraction makes that contradiction verifiably
+/// disappear, rather than just assuming removal works. Each candidate
+/// retraction is checked by actually rebuilding the graph without the
+/// retracted propositions and re-running `detect_contradictions`, so a
+/// suggestion reflects what the solver would conclude next, not a guess
+/// about which proposition was "most to blame."
+///
+/// Contradictions with no retractable assumption among their members are
+/// omitted — there's nothing to suggest retracting. The remaining
+/// suggestions are ranked by severity (critical first) so a user doing
+/// scenario planning sees the most urgent belief revisions first.
+pub fn suggest_retractions(graph: &LogicalGraph, contradictions: &[Contradiction]) -> Vec<RetractionSuggestion> {
+    let mut suggestions: Vec<RetractionSuggestion> = Vec::new();
+
+    for contradiction in contradictions {
+        let retractable: Vec<String> = contradiction
+            .proposition_ids
+            .iter()
+            .filter(|id| graph.get_proposition(id).map(|p| p.prop_type == "assumption").unwrap_or(false))
+            .cloned()
+            .collect();
+        if retractable.is_empty() {
+            continue;
+        }
+
+        let retract = minimal_retraction_set(graph, contradiction, &retractable);
+        if retract.is_empty() {
+            continue;
+        }
+
+        suggestions.push(RetractionSuggestion {
+            contradiction_id: contradiction.id.clone(),
+            retract,
+            severity: contradiction.severity.clone(),
+        });
+    }
+
+    suggestions.sort_by(|a, b| {
+        severity_rank(&b.severity)
+            .cmp(&severity_rank(&a.severity))
+            .then_with(|| a.contradiction_id.cmp(&b.contradiction_id))
+    });
+    suggestions
+}
+
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "critical" => 2,
+        "major" => 1,
+        _ => 0,
+    }
+}
+
+/// Build the graph as it would look with every id in `retracted` removed,
+/// along with any relationship that touches one of them.
+fn without_propositions(graph: &LogicalGraph, retracted: &HashSet<String>) -> LogicalGraph {
+    LogicalGraph {
+        propositions: graph.propositions.iter().filter(|p| !retracted.contains(&p.id)).cloned().collect(),
+        relationships: graph
+            .relationships
+            .iter()
+            .filter(|r| !retracted.contains(&r.from_id) && !retracted.contains(&r.to_id))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Whether `members` (a contradiction's proposition ids still standing after
+/// some retractions) would still be flagged together by a fresh solve: true
+/// if some contradiction from that fresh run fully contains them.
+fn still_triggers(contradictions: &[Contradiction], members: &HashSet<String>) -> bool {
+    !members.is_empty() && contradictions.iter().any(|c| members.iter().all(|id| c.proposition_ids.contains(id)))
+}
+
+/// Greedily retract candidates (least load-bearing first) until a fresh
+/// solve over the reduced graph no longer reproduces the contradiction,
+/// then shrink the result by dropping any member whose retraction turns out
+/// not to have been necessary. Returns an empty vec if retracting every
+/// candidate still doesn't resolve it.
+fn minimal_retraction_set(graph: &LogicalGraph, contradiction: &Contradiction, retractable: &[String]) -> Vec<String> {
+    let mut candidates = retractable.to_vec();
+    candidates.sort_by(|a, b| {
+        let a_load_bearing = graph.get_proposition(a).map(|p| p.is_load_bearing).unwrap_or(false);
+        let b_load_bearing = graph.get_proposition(b).map(|p| p.is_load_bearing).unwrap_or(false);
+        a_load_bearing.cmp(&b_load_bearing).then_with(|| a.cmp(b))
+    });
+
+    let defeated = |retracted: &HashSet<String>| -> bool {
+        let reduced = without_propositions(graph, retracted);
+        let rerun = detect_contradictions(&reduced);
+        let remaining: HashSet<String> =
+            contradiction.proposition_ids.iter().filter(|id| !retracted.contains(*id)).cloned().collect();
+        !still_triggers(&rerun, &remaining)
+    };
+
+    let mut retracted: HashSet<String> = HashSet::new();
+    for candidate in &candidates {
+        retracted.insert(candidate.clone());
+        if defeated(&retracted) {
+            break;
+        }
+    }
+    if !defeated(&retracted) {
+        return Vec::new();
+    }
+
+    for candidate in retracted.clone() {
+        let mut without = retracted.clone();
+        without.remove(&candidate);
+        if defeated(&without) {
+            retracted = without;
+        }
+    }
+
+    let mut result: Vec<String> = retracted.into_iter().collect();
+    result.sort();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_suggests_retracting_the_single_conflicting_assumption() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.statement = "We should pivot to Enterprise".to_string();
+        p0.formal_expression = "pivot_enterprise → true".to_string();
+        let mut p1 = make_prop("p1", "assumption", "medium");
+        p1.statement = "Rebuild takes >12 months".to_string();
+        p1.formal_expression = "rebuild_slow → true".to_string();
+
+        let graph = make_graph(vec![p0, p1], vec![make_rel("r1", "p1", "p0", "contradicts")]);
+
+        let contradictions = detect_contradictions(&graph);
+        let suggestions = suggest_retractions(&graph, &contradictions);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].retract, vec!["p1".to_string()]);
+    }
+
+    #[test]
+    fn test_no_suggestion_when_no_assumption_participates() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.formal_expression = "a → true".to_string();
+        let mut p1 = make_prop("p1", "evidence", "high");
+        p1.formal_expression = "b → true".to_string();
+
+        let graph = make_graph(vec![p0, p1], vec![make_rel("r1", "p1", "p0", "contradicts")]);
+
+        let contradictions = detect_contradictions(&graph);
+        assert!(suggest_retractions(&graph, &contradictions).is_empty());
+    }
+
+    #[test]
+    fn test_no_suggestion_when_graph_is_consistent() {
+        let mut p0 = make_prop("p0", "assumption", "medium");
+        p0.formal_expression = "growth → true".to_string();
+        let graph = make_graph(vec![p0], vec![]);
+
+        let contradictions = detect_contradictions(&graph);
+        assert!(suggest_retractions(&graph, &contradictions).is_empty());
+    }
+
+    #[test]
+    fn test_suggestions_ranked_critical_first() {
+        let mut load_bearing = make_prop("p0", "claim", "high");
+        load_bearing.statement = "Load-bearing claim".to_string();
+        load_bearing.formal_expression = "claim_a → true".to_string();
+        let mut assumption_a = make_prop("p1", "assumption", "medium");
+        assumption_a.formal_expression = "assume_a → true".to_string();
+
+        let mut minor_claim = make_prop("p2", "risk", "low");
+        minor_claim.formal_expression = "risk_b → true".to_string();
+        let mut assumption_b = make_prop("p3", "assumption", "medium");
+        assumption_b.formal_expression = "assume_b → true".to_string();
+
+        let graph = make_graph(
+            vec![load_bearing, assumption_a, minor_claim, assumption_b],
+            vec![
+                make_rel("r1", "p1", "p0", "contradicts"),
+                make_rel("r2", "p3", "p2", "contradicts"),
+            ],
+        );
+
+        let contradictions = detect_contradictions(&graph);
+        let suggestions = suggest_retractions(&graph, &contradictions);
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].severity, "critical");
+        assert_eq!(suggestions[1].severity, "major");
+    }
+}
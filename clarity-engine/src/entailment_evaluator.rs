@@ -0,0 +1,175 @@
+use crate::sat_solver::{atom_name, compile_to_cnf, parse_implication, propagate_units, split_conjuncts, Clause};
+use crate::types::{LogicalGraph, Question};
+use std::collections::{HashMap, HashSet};
+
+/// Evaluate whether `target_id`'s proposition is entailed, refuted, or left
+/// unknown given the rest of the graph — the true/false/unknown result
+/// model Prolog-style reasoners use for negation-as-failure. Jointly
+/// compiles every `formal_expression` to CNF (same compiler `sat_solver`
+/// uses) and unit-propagates to a fixpoint without branching, so the
+/// verdict reflects only what's logically forced, never a guess.
+///
+/// Where propagation stalls on an atom whose truth was never established
+/// by any proposition or relationship, the stalled branch is surfaced as a
+/// `Question` naming that atom, instead of being silently discarded.
+///
+/// Returns `"entailed"`, `"refuted"`, or `"unknown"` alongside any
+/// questions raised while reaching that verdict.
+pub fn evaluate(graph: &LogicalGraph, target_id: &str) -> (String, Vec<Question>) {
+    let Some(target) = graph.get_proposition(target_id) else {
+        return ("unknown".to_string(), Vec::new());
+    };
+
+    let (clauses, var_index) = compile_to_cnf(&graph.propositions);
+    let num_vars = var_index.len();
+    let all_literals: Vec<Vec<i32>> = clauses.iter().map(|c| c.literals.clone()).collect();
+
+    let Some(assignment) = propagate_units(&all_literals, vec![None; num_vars + 1]) else {
+        // The graph is already unsatisfiable by unit propagation alone —
+        // by the principle of explosion every proposition is vacuously
+        // both entailed and refuted. `sat_solver` owns reporting the
+        // underlying contradiction, so just report refuted here.
+        return ("refuted".to_string(), Vec::new());
+    };
+
+    let (atom, negated) = target_claim_literal(&target.formal_expression);
+    let Some(&var_id) = var_index.get(&atom) else {
+        return ("unknown".to_string(), Vec::new());
+    };
+
+    match assignment[var_id] {
+        Some(value) => {
+            let holds = value != negated;
+            (if holds { "entailed" } else { "refuted" }.to_string(), Vec::new())
+        }
+        None => {
+            let var_names: HashMap<usize, String> =
+                var_index.iter().map(|(name, id)| (*id, name.clone())).collect();
+            let questions = blocking_questions(&clauses, &assignment, var_id, &var_names, target_id);
+            ("unknown".to_string(), questions)
+        }
+    }
+}
+
+/// The atom a proposition makes a claim about, and whether that claim
+/// negates it: for a bare expression it's the expression itself, and for
+/// an implication it's the first conjunct of the consequent — the
+/// "conclusion" the proposition asserts follows from its antecedent.
+fn target_claim_literal(expr: &str) -> (String, bool) {
+    let claim = match parse_implication(expr) {
+        Some((_, rhs)) => split_conjuncts(&rhs).into_iter().next().unwrap_or_else(|| rhs.trim().to_string()),
+        None => split_conjuncts(expr).into_iter().next().unwrap_or_else(|| expr.trim().to_string()),
+    };
+    let trimmed = claim.trim();
+    let negated = trimmed.starts_with('¬') || trimmed.starts_with('!');
+    (atom_name(trimmed), negated)
+}
+
+/// Find every still-unassigned atom that, together with `var_id`, appears
+/// in a clause that isn't yet satisfied by anything else — i.e. every atom
+/// whose value would let propagation move forward on `var_id`. One
+/// `Question` per distinct blocking atom.
+fn blocking_questions(
+    clauses: &[Clause],
+    assignment: &[Option<bool>],
+    var_id: usize,
+    var_names: &HashMap<usize, String>,
+    target_id: &str,
+) -> Vec<Question> {
+    let mut questions = Vec::new();
+    let mut asked: HashSet<usize> = HashSet::new();
+
+    for clause in clauses {
+        if !clause.literals.iter().any(|lit| lit.unsigned_abs() as usize == var_id) {
+            continue;
+        }
+        let already_satisfied = clause
+            .literals
+            .iter()
+            .any(|&lit| assignment[lit.unsigned_abs() as usize].is_some_and(|value| (lit > 0) == value));
+        if already_satisfied {
+            continue;
+        }
+        for &lit in &clause.literals {
+            let id = lit.unsigned_abs() as usize;
+            if id == var_id || assignment[id].is_some() || !asked.insert(id) {
+                continue;
+            }
+            let name = var_names.get(&id).cloned().unwrap_or_else(|| id.to_string());
+            questions.push(Question {
+                target_id: target_id.to_string(),
+                unbound_atom: name.clone(),
+                blocking_prop_id: clause.source_prop.clone(),
+                text: format!("Is {} true?", name),
+            });
+        }
+    }
+
+    questions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_bare_atom_is_entailed_when_asserted() {
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
+        let graph = make_graph(vec![p0], vec![]);
+
+        let (verdict, questions) = evaluate(&graph, "p0");
+        assert_eq!(verdict, "entailed");
+        assert!(questions.is_empty());
+    }
+
+    #[test]
+    fn test_implication_consequent_entailed_when_antecedent_known() {
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "growth → success".to_string();
+        let graph = make_graph(vec![p0, p1], vec![]);
+
+        let (verdict, questions) = evaluate(&graph, "p1");
+        assert_eq!(verdict, "entailed");
+        assert!(questions.is_empty());
+    }
+
+    #[test]
+    fn test_unestablished_antecedent_yields_unknown_with_question() {
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "X → Y".to_string();
+        let graph = make_graph(vec![p1], vec![]);
+
+        let (verdict, questions) = evaluate(&graph, "p1");
+        assert_eq!(verdict, "unknown");
+        assert_eq!(questions.len(), 1);
+        assert_eq!(questions[0].unbound_atom, "X");
+        assert_eq!(questions[0].text, "Is X true?");
+    }
+
+    #[test]
+    fn test_global_conflict_reports_refuted() {
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "growth → ¬success".to_string();
+        let mut p2 = make_prop("p2", "evidence", "high");
+        p2.formal_expression = "success".to_string();
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
+
+        let (verdict, questions) = evaluate(&graph, "p2");
+        assert_eq!(verdict, "refuted");
+        assert!(questions.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_proposition_id_returns_unknown() {
+        let graph = make_graph(vec![], vec![]);
+        let (verdict, questions) = evaluate(&graph, "missing");
+        assert_eq!(verdict, "unknown");
+        assert!(questions.is_empty());
+    }
+}
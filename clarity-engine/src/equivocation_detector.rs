@@ -0,0 +1,225 @@
+use crate::types::{Contradiction, Equivocation, LogicalGraph};
+use std::collections::HashSet;
+
+/// Detect equivocation: a single author/source that is the origin of two
+/// mutually contradictory propositions, whether they contradict directly or
+/// by each supporting a different claim where those claims contradict each
+/// other.
+///
+/// Borrows the "double-vote" idea from BFT finality protocols: a validator
+/// that signs two conflicting blocks at the same height is equivocating, and
+/// so is a witness whose testimony backs both sides of a dispute — a source
+/// that argues both ways provides no net support, distinct from the
+/// graph-level contradiction list.
+pub fn detect_equivocations(
+    graph: &LogicalGraph,
+    contradictions: &[Contradiction],
+) -> Vec<Equivocation> {
+    let mut equivocations = Vec::new();
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+
+    // Case 1: the source authored two propositions that appear together in
+    // an existing Contradiction.
+    for contradiction in contradictions {
+        let ids = &contradiction.proposition_ids;
+        for i in 0..ids.len() {
+            for j in (i + 1)..ids.len() {
+                let (Some(p1), Some(p2)) =
+                    (graph.get_proposition(&ids[i]), graph.get_proposition(&ids[j]))
+                else {
+                    continue;
+                };
+                let (Some(s1), Some(s2)) = (p1.source.as_deref(), p2.source.as_deref()) else {
+                    continue;
+                };
+                if s1 != s2 {
+                    continue;
+                }
+                record(&mut equivocations, &mut seen, s1, &p1.id, &p2.id, &contradiction.id);
+            }
+        }
+    }
+
+    // Case 2: the source authored two propositions that each `support` a
+    // different claim, and those two claims themselves contradict each
+    // other — the source never directly contradicts itself, but backs both
+    // sides of the dispute one hop removed.
+    let supports: Vec<&crate::types::Relationship> = graph
+        .relationships
+        .iter()
+        .filter(|r| r.rel_type == "supports")
+        .collect();
+
+    for i in 0..supports.len() {
+        for j in (i + 1)..supports.len() {
+            let (r1, r2) = (supports[i], supports[j]);
+            if r1.to_id == r2.to_id {
+                continue;
+            }
+            let (Some(p1), Some(p2)) = (
+                graph.get_proposition(&r1.from_id),
+                graph.get_proposition(&r2.from_id),
+            ) else {
+                continue;
+            };
+            let (Some(s1), Some(s2)) = (p1.source.as_deref(), p2.source.as_deref()) else {
+                continue;
+            };
+            if s1 != s2 {
+                continue;
+            }
+
+            if let Some(contradiction_id) =
+                targets_contradict(graph, contradictions, &r1.to_id, &r2.to_id)
+            {
+                record(&mut equivocations, &mut seen, s1, &p1.id, &p2.id, &contradiction_id);
+            }
+        }
+    }
+
+    equivocations
+}
+
+/// Whether `a` and `b` contradict each other, either via a raw `contradicts`
+/// relationship or by appearing together in a detected `Contradiction`.
+/// Returns the id to attribute the equivocation to, synthesizing a stable one
+/// when the conflict is only visible at the raw-edge level.
+fn targets_contradict(
+    graph: &LogicalGraph,
+    contradictions: &[Contradiction],
+    a: &str,
+    b: &str,
+) -> Option<String> {
+    if let Some(c) = contradictions
+        .iter()
+        .find(|c| c.proposition_ids.iter().any(|id| id == a) && c.proposition_ids.iter().any(|id| id == b))
+    {
+        return Some(c.id.clone());
+    }
+    let directly_contradicts = graph.relationships.iter().any(|r| {
+        r.rel_type == "contradicts"
+            && ((r.from_id == a && r.to_id == b) || (r.from_id == b && r.to_id == a))
+    });
+    if directly_contradicts {
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        return Some(format!("derived-{}-{}", lo, hi));
+    }
+    None
+}
+
+fn record(
+    out: &mut Vec<Equivocation>,
+    seen: &mut HashSet<(String, String)>,
+    source_id: &str,
+    first_prop: &str,
+    second_prop: &str,
+    contradiction_id: &str,
+) {
+    let key = if first_prop < second_prop {
+        (first_prop.to_string(), second_prop.to_string())
+    } else {
+        (second_prop.to_string(), first_prop.to_string())
+    };
+    if !seen.insert(key) {
+        return;
+    }
+    out.push(Equivocation {
+        source_id: source_id.to_string(),
+        first_prop: first_prop.to_string(),
+        second_prop: second_prop.to_string(),
+        contradiction_id: contradiction_id.to_string(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_equivocation_via_shared_contradiction() {
+        let mut p1 = make_prop("P1", "claim", "high");
+        p1.source = Some("witness-1".to_string());
+        let mut p2 = make_prop("P2", "claim", "high");
+        p2.source = Some("witness-1".to_string());
+
+        let graph = make_graph(vec![p1, p2], vec![]);
+        let contradiction = Contradiction {
+            id: "c1".to_string(),
+            proposition_ids: vec!["P1".to_string(), "P2".to_string()],
+            contradiction_type: "logical".to_string(),
+            severity: "critical".to_string(),
+            formal_proof: "test".to_string(),
+            human_explanation: "test".to_string(),
+            proof: Vec::new(),
+        };
+
+        let equivocations = detect_equivocations(&graph, &[contradiction]);
+        assert_eq!(equivocations.len(), 1);
+        assert_eq!(equivocations[0].source_id, "witness-1");
+        assert_eq!(equivocations[0].contradiction_id, "c1");
+    }
+
+    #[test]
+    fn test_equivocation_via_contradicting_supported_claims() {
+        let mut e1 = make_prop("E1", "evidence", "high");
+        e1.source = Some("witness-1".to_string());
+        let mut e2 = make_prop("E2", "evidence", "high");
+        e2.source = Some("witness-1".to_string());
+        let c1 = make_prop("C1", "claim", "high");
+        let c2 = make_prop("C2", "claim", "high");
+
+        let graph = make_graph(
+            vec![e1, e2, c1, c2],
+            vec![
+                make_rel("r1", "E1", "C1", "supports"),
+                make_rel("r2", "E2", "C2", "supports"),
+                make_rel("r3", "C1", "C2", "contradicts"),
+            ],
+        );
+
+        let equivocations = detect_equivocations(&graph, &[]);
+        assert_eq!(equivocations.len(), 1);
+        assert_eq!(equivocations[0].source_id, "witness-1");
+        assert_eq!(equivocations[0].first_prop, "E1");
+        assert_eq!(equivocations[0].second_prop, "E2");
+    }
+
+    #[test]
+    fn test_no_equivocation_with_different_sources() {
+        let mut e1 = make_prop("E1", "evidence", "high");
+        e1.source = Some("witness-1".to_string());
+        let mut e2 = make_prop("E2", "evidence", "high");
+        e2.source = Some("witness-2".to_string());
+        let c1 = make_prop("C1", "claim", "high");
+        let c2 = make_prop("C2", "claim", "high");
+
+        let graph = make_graph(
+            vec![e1, e2, c1, c2],
+            vec![
+                make_rel("r1", "E1", "C1", "supports"),
+                make_rel("r2", "E2", "C2", "supports"),
+                make_rel("r3", "C1", "C2", "contradicts"),
+            ],
+        );
+
+        assert!(detect_equivocations(&graph, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_no_equivocation_without_source_field() {
+        let e1 = make_prop("E1", "evidence", "high");
+        let e2 = make_prop("E2", "evidence", "high");
+        let graph = make_graph(vec![e1, e2], vec![]);
+        let contradiction = Contradiction {
+            id: "c1".to_string(),
+            proposition_ids: vec!["E1".to_string(), "E2".to_string()],
+            contradiction_type: "logical".to_string(),
+            severity: "critical".to_string(),
+            formal_proof: "test".to_string(),
+            human_explanation: "test".to_string(),
+            proof: Vec::new(),
+        };
+        assert!(detect_equivocations(&graph, &[contradiction]).is_empty());
+    }
+}
@@ -1,5 +1,16 @@
 use crate::types::LogicalGraph;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+
+/// Node count above which `betweenness_centrality_bounded` switches from
+/// exact Brandes (every node as a source) to sampling a fixed-size subset —
+/// the default for `types::SearchBudget::centrality_sampling_threshold`.
+pub const DEFAULT_SAMPLING_THRESHOLD: usize = 500;
+
+/// Default number of source nodes sampled once `DEFAULT_SAMPLING_THRESHOLD`
+/// is exceeded — the default for `types::SearchBudget::centrality_sample_size`.
+pub const DEFAULT_SAMPLE_SIZE: usize = 100;
 
 /// Compute betweenness centrality for each node using Brandes' algorithm.
 ///
@@ -8,6 +19,12 @@ use std::collections::{HashMap, VecDeque};
 ///
 /// Only follows dependency edges ("supports", "depends_on", "assumes").
 /// Normalizes scores to 0.0–1.0 range.
+///
+/// Runs Brandes from every node as a source — exact, but `O(n * (n + e))`,
+/// which is too slow on a graph of thousands of nodes. `pipeline` calls
+/// `betweenness_centrality_bounded` instead, which falls back to sampling
+/// above a node-count threshold; this function stays exact and unbounded for
+/// direct callers (and the tests below) that want the precise score.
 pub fn betweenness_centrality(graph: &LogicalGraph) -> HashMap<String, f64> {
     let n = graph.propositions.len();
     let adj = graph.get_dependency_adjacency();
@@ -22,96 +39,231 @@ pub fn betweenness_centrality(graph: &LogicalGraph) -> HashMap<String, f64> {
         return centrality;
     }
 
-    // Brandes' algorithm: for each source node, BFS to find shortest paths,
-    // then backtrack to accumulate betweenness contributions.
     for &source in &ids {
-        // BFS from source
-        let mut stack: Vec<&str> = Vec::new();
-        let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
-        let mut sigma: HashMap<&str, f64> = HashMap::new(); // number of shortest paths
-        let mut dist: HashMap<&str, i64> = HashMap::new();  // distance from source
-
-        for &id in &ids {
-            predecessors.insert(id, Vec::new());
-            sigma.insert(id, 0.0);
-            dist.insert(id, -1);
-        }
-        sigma.insert(source, 1.0);
-        dist.insert(source, 0);
-
-        let mut queue: VecDeque<&str> = VecDeque::new();
-        queue.push_back(source);
-
-        while let Some(v) = queue.pop_front() {
-            stack.push(v);
-            let v_dist = dist[v];
-
-            if let Some(neighbors) = adj.get(v) {
-                for neighbor in neighbors {
-                    let w = neighbor.as_str();
-                    // Find w in our id set (ensure it's a valid node)
-                    if !dist.contains_key(w) {
-                        continue;
-                    }
-
-                    // w found for the first time?
-                    if dist[w] < 0 {
-                        dist.insert(w, v_dist + 1);
-                        queue.push_back(
-                            ids.iter().find(|&&id| id == w).copied().unwrap_or(w)
-                        );
-                    }
-
-                    // shortest path to w via v?
-                    if dist[w] == v_dist + 1 {
-                        *sigma.get_mut(w).unwrap() += sigma[v];
-                        predecessors.get_mut(w).unwrap().push(v);
-                    }
+        accumulate_brandes_source(source, &ids, &adj, &mut centrality);
+    }
+
+    normalize_centrality(&mut centrality, n);
+    centrality
+}
+
+/// Like `betweenness_centrality`, but switches to sampling once the graph
+/// has more than `sampling_threshold` propositions: Brandes is run from only
+/// `sample_size` source nodes instead of every node, and every node's
+/// accumulated contribution is scaled by `n / sample_size` to estimate the
+/// score exact Brandes would have produced. Sampled sources are chosen
+/// deterministically — by hashing each id and keeping the `sample_size`
+/// smallest hashes — rather than via a `rand` dependency this crate doesn't
+/// otherwise need, so the same graph always samples the same subset and a
+/// result is reproducible. Returns the scores alongside whether sampling was
+/// used, so a caller can tell an estimate from an exact result.
+pub fn betweenness_centrality_bounded(
+    graph: &LogicalGraph,
+    sampling_threshold: usize,
+    sample_size: usize,
+) -> (HashMap<String, f64>, bool) {
+    let n = graph.propositions.len();
+    if n <= sampling_threshold || sample_size == 0 || sample_size >= n {
+        return (betweenness_centrality(graph), false);
+    }
+
+    let adj = graph.get_dependency_adjacency();
+    let ids: Vec<&str> = graph.propositions.iter().map(|p| p.id.as_str()).collect();
+
+    let mut centrality: HashMap<String, f64> = HashMap::new();
+    for id in &ids {
+        centrality.insert(id.to_string(), 0.0);
+    }
+
+    let mut sources: Vec<&str> = ids.clone();
+    sources.sort_by_key(|id| hash_id(id));
+    sources.truncate(sample_size);
+
+    for &source in &sources {
+        accumulate_brandes_source(source, &ids, &adj, &mut centrality);
+    }
+
+    let scale = n as f64 / sample_size as f64;
+    for val in centrality.values_mut() {
+        *val *= scale;
+    }
+    normalize_centrality(&mut centrality, n);
+
+    (centrality, true)
+}
+
+fn hash_id(id: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    id.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// One source node's pass of Brandes' algorithm: BFS out from `source` to
+/// find shortest paths, then back-propagate to accumulate each node's
+/// betweenness contribution into `centrality`. Shared by the exact and
+/// sampled entry points above so the accumulation logic exists only once.
+fn accumulate_brandes_source<'a>(
+    source: &'a str,
+    ids: &[&'a str],
+    adj: &HashMap<String, Vec<String>>,
+    centrality: &mut HashMap<String, f64>,
+) {
+    let mut stack: Vec<&str> = Vec::new();
+    let mut predecessors: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut sigma: HashMap<&str, f64> = HashMap::new(); // number of shortest paths
+    let mut dist: HashMap<&str, i64> = HashMap::new(); // distance from source
+
+    for &id in ids {
+        predecessors.insert(id, Vec::new());
+        sigma.insert(id, 0.0);
+        dist.insert(id, -1);
+    }
+    sigma.insert(source, 1.0);
+    dist.insert(source, 0);
+
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(source);
+
+    while let Some(v) = queue.pop_front() {
+        stack.push(v);
+        let v_dist = dist[v];
+
+        if let Some(neighbors) = adj.get(v) {
+            for neighbor in neighbors {
+                let w = neighbor.as_str();
+                // Find w in our id set (ensure it's a valid node)
+                if !dist.contains_key(w) {
+                    continue;
                 }
-            }
-        }
 
-        // Back-propagation of dependencies
-        let mut delta: HashMap<&str, f64> = HashMap::new();
-        for &id in &ids {
-            delta.insert(id, 0.0);
-        }
+                // w found for the first time?
+                if dist[w] < 0 {
+                    dist.insert(w, v_dist + 1);
+                    queue.push_back(ids.iter().find(|&&id| id == w).copied().unwrap_or(w));
+                }
 
-        while let Some(w) = stack.pop() {
-            if w == source {
-                continue;
-            }
-            let sigma_w = sigma[w];
-            if sigma_w == 0.0 {
-                continue;
+                // shortest path to w via v?
+                if dist[w] == v_dist + 1 {
+                    *sigma.get_mut(w).unwrap() += sigma[v];
+                    predecessors.get_mut(w).unwrap().push(v);
+                }
             }
+        }
+    }
 
-            for &v in &predecessors[w] {
-                let contribution = (sigma[v] / sigma_w) * (1.0 + delta[w]);
-                *delta.get_mut(v).unwrap() += contribution;
-            }
+    // Back-propagation of dependencies
+    let mut delta: HashMap<&str, f64> = HashMap::new();
+    for &id in ids {
+        delta.insert(id, 0.0);
+    }
 
-            // For undirected graphs we'd divide by 2, but our graph is directed
-            *centrality.get_mut(w).unwrap() += delta[w];
+    while let Some(w) = stack.pop() {
+        if w == source {
+            continue;
         }
+        let sigma_w = sigma[w];
+        if sigma_w == 0.0 {
+            continue;
+        }
+
+        for &v in &predecessors[w] {
+            let contribution = (sigma[v] / sigma_w) * (1.0 + delta[w]);
+            *delta.get_mut(v).unwrap() += contribution;
+        }
+
+        // For undirected graphs we'd divide by 2, but our graph is directed
+        *centrality.get_mut(w).unwrap() += delta[w];
     }
+}
 
-    // Normalize to 0.0–1.0
-    let normalization = if n > 2 {
-        ((n - 1) * (n - 2)) as f64
-    } else {
-        1.0
-    };
+/// Normalize accumulated betweenness contributions to the 0.0–1.0 range and
+/// clamp away any floating-point overshoot.
+fn normalize_centrality(centrality: &mut HashMap<String, f64>, n: usize) {
+    let normalization = if n > 2 { ((n - 1) * (n - 2)) as f64 } else { 1.0 };
 
     for val in centrality.values_mut() {
         *val /= normalization;
-        // Clamp to [0, 1]
         if *val > 1.0 {
             *val = 1.0;
         }
     }
+}
 
-    centrality
+/// Compute PageRank over the `supports` edges via power iteration.
+///
+/// Where betweenness measures "how many argument paths pass through a node,"
+/// PageRank measures "how much authority flows into a claim" — a claim backed
+/// by already-well-supported evidence ranks higher than one backed by an
+/// isolated assertion, even if neither sits on many shortest paths.
+///
+/// `damping` is the standard PageRank damping factor (typically 0.85).
+/// Dangling nodes (no outgoing `supports` edge) redistribute their mass
+/// uniformly across every node, so the rank vector stays normalized to 1.0.
+/// Iterates until the L1 delta between successive iterations falls below
+/// `1e-6` or `iters` is reached.
+pub fn pagerank(graph: &LogicalGraph, damping: f64, iters: usize) -> HashMap<String, f64> {
+    let ids: Vec<&str> = graph.propositions.iter().map(|p| p.id.as_str()).collect();
+    let n = ids.len();
+
+    let mut rank: HashMap<&str, f64> = HashMap::new();
+    if n == 0 {
+        return HashMap::new();
+    }
+    for &id in &ids {
+        rank.insert(id, 1.0 / n as f64);
+    }
+
+    // Build supports-only adjacency (from → [to, ...]) and out-degree.
+    let mut supports_adj: HashMap<&str, Vec<&str>> = HashMap::new();
+    for &id in &ids {
+        supports_adj.entry(id).or_default();
+    }
+    for rel in &graph.relationships {
+        if rel.rel_type == "supports" {
+            if let (Some(&from), Some(&to)) = (
+                ids.iter().find(|&&i| i == rel.from_id),
+                ids.iter().find(|&&i| i == rel.to_id),
+            ) {
+                supports_adj.entry(from).or_default().push(to);
+            }
+        }
+    }
+
+    const TOLERANCE: f64 = 1e-6;
+    let base_share = (1.0 - damping) / n as f64;
+
+    for _ in 0..iters {
+        let dangling_mass: f64 = ids
+            .iter()
+            .filter(|&&id| supports_adj.get(id).map(|v| v.is_empty()).unwrap_or(true))
+            .map(|&id| rank[id])
+            .sum();
+        let dangling_share = damping * dangling_mass / n as f64;
+
+        let mut next: HashMap<&str, f64> = HashMap::new();
+        for &v in &ids {
+            next.insert(v, base_share + dangling_share);
+        }
+
+        for &u in &ids {
+            let outdeg = supports_adj[u].len();
+            if outdeg == 0 {
+                continue;
+            }
+            let share = damping * rank[u] / outdeg as f64;
+            for &v in &supports_adj[u] {
+                *next.get_mut(v).unwrap() += share;
+            }
+        }
+
+        let delta: f64 = ids.iter().map(|&id| (next[id] - rank[id]).abs()).sum();
+        rank = next;
+        if delta < TOLERANCE {
+            break;
+        }
+    }
+
+    rank.into_iter().map(|(id, r)| (id.to_string(), r)).collect()
 }
 
 #[cfg(test)]
@@ -200,4 +352,79 @@ mod tests {
         let centrality = betweenness_centrality(&graph);
         assert_eq!(centrality["A"], 0.0);
     }
+
+    #[test]
+    fn test_bounded_centrality_below_threshold_is_exact() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "evidence", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "assumption", "medium"),
+            ],
+            vec![make_rel("r1", "A", "C", "supports"), make_rel("r2", "B", "C", "supports")],
+        );
+        let (bounded, approximate) = betweenness_centrality_bounded(&graph, 10, 2);
+        assert!(!approximate);
+        assert_eq!(bounded, betweenness_centrality(&graph));
+    }
+
+    #[test]
+    fn test_bounded_centrality_above_threshold_samples_and_flags_approximate() {
+        let props: Vec<_> = (0..20).map(|i| make_prop(&format!("n{i}"), "evidence", "high")).collect();
+        let rels: Vec<_> = (0..19).map(|i| make_rel(&format!("r{i}"), &format!("n{i}"), &format!("n{}", i + 1), "supports")).collect();
+        let graph = make_graph(props, rels);
+
+        let (sampled, approximate) = betweenness_centrality_bounded(&graph, 5, 5);
+        assert!(approximate);
+        assert_eq!(sampled.len(), 20);
+        // Every score should still land in the normalized range.
+        assert!(sampled.values().all(|&v| (0.0..=1.0).contains(&v)));
+    }
+
+    #[test]
+    fn test_pagerank_sums_to_one() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "evidence", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "C", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+            ],
+        );
+        let ranks = pagerank(&graph, 0.85, 100);
+        let total: f64 = ranks.values().sum();
+        assert!((total - 1.0).abs() < 1e-6, "PageRank should stay normalized, got {}", total);
+    }
+
+    #[test]
+    fn test_pagerank_well_supported_claim_ranks_higher() {
+        // A, B both support C; C supports D. D should outrank an isolated
+        // leaf with no incoming support.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "evidence", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "claim", "high"),
+                make_prop("D", "claim", "high"),
+                make_prop("E", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "C", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+                make_rel("r3", "C", "D", "supports"),
+            ],
+        );
+        let ranks = pagerank(&graph, 0.85, 100);
+        assert!(ranks["D"] > ranks["E"], "Claim backed by well-supported evidence should outrank an isolated assertion");
+    }
+
+    #[test]
+    fn test_pagerank_empty_graph() {
+        let graph = make_graph(vec![], vec![]);
+        let ranks = pagerank(&graph, 0.85, 100);
+        assert!(ranks.is_empty());
+    }
 }
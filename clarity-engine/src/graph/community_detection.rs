@@ -0,0 +1,279 @@
+use crate::types::{Community, Contradiction, LogicalGraph};
+use std::collections::HashMap;
+
+/// Cluster the argument graph into opposing "camps" via Louvain modularity
+/// optimization.
+///
+/// `supports`/`depends_on` edges are treated as positive attraction between
+/// propositions; raw `contradicts` edges and the pairs named by `contradictions`
+/// are treated as a negative/penalty signal, so propositions that contradict
+/// each other are pulled toward different camps. Each node starts in its own
+/// community; nodes are repeatedly moved into the neighboring community that
+/// yields the largest modularity gain until no move improves it, then
+/// communities are contracted into super-nodes and the process repeats on the
+/// condensed graph until it stops shrinking.
+pub fn detect_argument_camps(
+    graph: &LogicalGraph,
+    contradictions: &[Contradiction],
+) -> Vec<Community> {
+    let ids: Vec<String> = graph.propositions.iter().map(|p| p.id.clone()).collect();
+    let n = ids.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let index_of: HashMap<&str, usize> =
+        ids.iter().enumerate().map(|(i, id)| (id.as_str(), i)).collect();
+
+    let mut weight: HashMap<(usize, usize), f64> = HashMap::new();
+    for rel in &graph.relationships {
+        let (Some(&a), Some(&b)) = (
+            index_of.get(rel.from_id.as_str()),
+            index_of.get(rel.to_id.as_str()),
+        ) else {
+            continue;
+        };
+        match rel.rel_type.as_str() {
+            "supports" | "depends_on" => add_weight(&mut weight, a, b, 1.0),
+            "contradicts" => add_weight(&mut weight, a, b, -1.0),
+            _ => {}
+        }
+    }
+    for c in contradictions {
+        for i in 0..c.proposition_ids.len() {
+            for j in (i + 1)..c.proposition_ids.len() {
+                if let (Some(&a), Some(&b)) = (
+                    index_of.get(c.proposition_ids[i].as_str()),
+                    index_of.get(c.proposition_ids[j].as_str()),
+                ) {
+                    add_weight(&mut weight, a, b, -1.0);
+                }
+            }
+        }
+    }
+
+    // node_to_level[i] tracks which current-level super-node original node i belongs to.
+    let mut node_to_level: Vec<usize> = (0..n).collect();
+    let mut level_adj = weight;
+    let mut level_n = n;
+
+    // Repeatedly run local-moving on the current level, contract into
+    // super-nodes, and recurse on the condensed graph until a round produces
+    // no further merging (or we hit a sane iteration cap).
+    for _ in 0..20 {
+        let community = local_moving(level_n, &level_adj);
+        let renumbered = renumber(&community, level_n);
+        let next_n = renumbered.iter().copied().max().map(|m| m + 1).unwrap_or(0);
+
+        for slot in node_to_level.iter_mut() {
+            *slot = renumbered[*slot];
+        }
+
+        if next_n == level_n {
+            break;
+        }
+        level_adj = contract(&level_adj, &renumbered);
+        level_n = next_n;
+    }
+
+    let mut groups: HashMap<usize, Vec<String>> = HashMap::new();
+    for (i, id) in ids.iter().enumerate() {
+        groups.entry(node_to_level[i]).or_default().push(id.clone());
+    }
+
+    let mut communities: Vec<Community> = groups
+        .into_iter()
+        .map(|(camp, mut members)| {
+            members.sort();
+            Community {
+                id: format!("camp-{}", camp),
+                proposition_ids: members,
+            }
+        })
+        .collect();
+    communities.sort_by(|a, b| a.id.cmp(&b.id));
+    communities
+}
+
+fn add_weight(weight: &mut HashMap<(usize, usize), f64>, a: usize, b: usize, w: f64) {
+    if a == b {
+        return;
+    }
+    let key = if a < b { (a, b) } else { (b, a) };
+    *weight.entry(key).or_insert(0.0) += w;
+}
+
+/// One Louvain "local moving" phase: repeatedly move each node into the
+/// neighboring community with the largest modularity gain until a full pass
+/// makes no move. Returns a community id per node (not necessarily contiguous).
+fn local_moving(n: usize, adj: &HashMap<(usize, usize), f64>) -> Vec<usize> {
+    let mut neighbors: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    for (&(a, b), &w) in adj {
+        neighbors[a].push((b, w));
+        if a != b {
+            neighbors[b].push((a, w));
+        }
+    }
+
+    let degree: Vec<f64> = (0..n)
+        .map(|i| {
+            neighbors[i]
+                .iter()
+                .map(|&(j, w)| if j == i { 2.0 * w } else { w })
+                .sum()
+        })
+        .collect();
+    let m: f64 = adj.values().sum();
+
+    let mut community: Vec<usize> = (0..n).collect();
+    if m == 0.0 {
+        return community;
+    }
+    let mut sigma_tot: Vec<f64> = degree.clone();
+
+    for _ in 0..50 {
+        let mut improved = false;
+        for i in 0..n {
+            let current = community[i];
+            sigma_tot[current] -= degree[i];
+
+            let mut weight_to_comm: HashMap<usize, f64> = HashMap::new();
+            for &(j, w) in &neighbors[i] {
+                if j == i {
+                    continue;
+                }
+                *weight_to_comm.entry(community[j]).or_insert(0.0) += w;
+            }
+
+            let mut best_comm = current;
+            let mut best_gain = weight_to_comm.get(&current).copied().unwrap_or(0.0) / m
+                - sigma_tot[current] * degree[i] / (2.0 * m * m);
+
+            for (&c, &k_i_in_c) in &weight_to_comm {
+                if c == current {
+                    continue;
+                }
+                let gain = k_i_in_c / m - sigma_tot[c] * degree[i] / (2.0 * m * m);
+                if gain > best_gain {
+                    best_gain = gain;
+                    best_comm = c;
+                }
+            }
+
+            sigma_tot[best_comm] += degree[i];
+            community[i] = best_comm;
+            if best_comm != current {
+                improved = true;
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    community
+}
+
+/// Renumber arbitrary community ids into a contiguous `0..k` range.
+fn renumber(community: &[usize], n: usize) -> Vec<usize> {
+    let mut next_id: HashMap<usize, usize> = HashMap::new();
+    let mut out = vec![0usize; n];
+    for i in 0..n {
+        let k = next_id.len();
+        let id = *next_id.entry(community[i]).or_insert(k);
+        out[i] = id;
+    }
+    out
+}
+
+/// Contract the current-level graph into `next_n` super-nodes, summing edge
+/// weights (including self-loops, which capture intra-community weight so the
+/// next local-moving pass can still compute correct modularity gains).
+fn contract(
+    adj: &HashMap<(usize, usize), f64>,
+    renumbered: &[usize],
+) -> HashMap<(usize, usize), f64> {
+    let mut next_adj: HashMap<(usize, usize), f64> = HashMap::new();
+    for (&(a, b), &w) in adj {
+        let (ca, cb) = (renumbered[a], renumbered[b]);
+        let key = if ca <= cb { (ca, cb) } else { (cb, ca) };
+        *next_adj.entry(key).or_insert(0.0) += w;
+    }
+    next_adj
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_two_supportive_cliques_form_separate_camps() {
+        let graph = make_graph(
+            vec![
+                make_prop("A1", "evidence", "high"),
+                make_prop("A2", "evidence", "high"),
+                make_prop("A3", "claim", "high"),
+                make_prop("B1", "evidence", "high"),
+                make_prop("B2", "evidence", "high"),
+                make_prop("B3", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A1", "A3", "supports"),
+                make_rel("r2", "A2", "A3", "supports"),
+                make_rel("r3", "B1", "B3", "supports"),
+                make_rel("r4", "B2", "B3", "supports"),
+                make_rel("r5", "A3", "B3", "contradicts"),
+            ],
+        );
+        let camps = detect_argument_camps(&graph, &[]);
+        let camp_of = |id: &str| {
+            camps
+                .iter()
+                .position(|c| c.proposition_ids.iter().any(|p| p == id))
+                .unwrap()
+        };
+        assert_eq!(camp_of("A1"), camp_of("A2"));
+        assert_eq!(camp_of("A1"), camp_of("A3"));
+        assert_eq!(camp_of("B1"), camp_of("B2"));
+        assert_eq!(camp_of("B1"), camp_of("B3"));
+        assert_ne!(camp_of("A1"), camp_of("B1"));
+    }
+
+    #[test]
+    fn test_contradiction_list_pushes_camps_apart() {
+        // No raw "contradicts" edge in the graph itself — the signal comes
+        // purely from the detected `Contradiction` records.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "claim", "high"),
+            ],
+            vec![make_rel("r1", "A", "B", "supports")],
+        );
+        let contradictions = vec![Contradiction {
+            id: "c1".to_string(),
+            proposition_ids: vec!["A".to_string(), "B".to_string()],
+            contradiction_type: "logical".to_string(),
+            severity: "critical".to_string(),
+            formal_proof: "A ∧ ¬A".to_string(),
+            human_explanation: "test".to_string(),
+            proof: Vec::new(),
+        }];
+        let camps = detect_argument_camps(&graph, &contradictions);
+        let camp_of = |id: &str| {
+            camps
+                .iter()
+                .position(|c| c.proposition_ids.iter().any(|p| p == id))
+                .unwrap()
+        };
+        assert_ne!(camp_of("A"), camp_of("B"));
+    }
+
+    #[test]
+    fn test_empty_graph_has_no_camps() {
+        let graph = make_graph(vec![], vec![]);
+        let camps = detect_argument_camps(&graph, &[]);
+        assert!(camps.is_empty());
+    }
+}
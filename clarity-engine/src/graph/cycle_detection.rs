@@ -1,4 +1,4 @@
-use crate::types::LogicalGraph;
+use crate::types::{LogicalGraph, ReasoningCycle};
 use std::collections::{HashMap, HashSet};
 
 /// Detect all cycles in the logical graph using DFS with three-color marking.
@@ -112,6 +112,291 @@ fn normalize_cycle(cycle: &[String]) -> Vec<String> {
     normalized
 }
 
+/// Find circular-reasoning clusters using Tarjan's strongly-connected-components
+/// algorithm over the dependency adjacency.
+///
+/// Unlike `detect_cycles` (which enumerates individual back-edge loops), this
+/// groups nodes that mutually reach each other into one component — any
+/// component of size ≥ 2, or a singleton with a self-edge, means every
+/// proposition inside it ultimately supports itself with no independent
+/// grounding.
+pub fn find_circular_reasoning(graph: &LogicalGraph) -> Vec<ReasoningCycle> {
+    let adj = graph.get_dependency_adjacency();
+
+    strongly_connected_components(&adj)
+        .into_iter()
+        .filter(|component| {
+            if component.len() >= 2 {
+                return true;
+            }
+            // Singleton component: only circular if it has a self-edge.
+            let node = &component[0];
+            adj.get(node).map(|n| n.contains(node)).unwrap_or(false)
+        })
+        .map(|proposition_ids| ReasoningCycle { proposition_ids })
+        .collect()
+}
+
+/// Enumerate every *elementary* (simple, non-repeating) cycle in the
+/// dependency graph, rather than one cycle per DFS back edge.
+///
+/// `detect_cycles`'s single DFS pass finds one loop per back edge it
+/// encounters, so when several circular arguments share a strongly-connected
+/// component, it under-reports them (or blends them into a single path).
+/// This instead partitions the graph into strongly-connected components via
+/// Tarjan's algorithm, then — for each component — enumerates every
+/// elementary cycle within it via Johnson's algorithm: repeatedly take the
+/// least remaining node `s`, find the strongly-connected component of `s`
+/// within the nodes still active, and run a DFS from `s` that blocks nodes
+/// once visited and only unblocks them (via the `B` map) once a path through
+/// them is proven to reach back to `s`, emitting the stack as a cycle on
+/// every return to `s`. Removing `s` afterwards guarantees no cycle is
+/// emitted twice and every elementary cycle is found exactly once.
+pub fn enumerate_elementary_cycles(graph: &LogicalGraph) -> Vec<Vec<String>> {
+    enumerate_elementary_cycles_bounded(graph, usize::MAX, usize::MAX).0
+}
+
+/// Like `enumerate_elementary_cycles`, but gives up once `max_cycles` cycles
+/// have been emitted or `max_visits` circuit-search steps have been taken
+/// (one step per `find_circuits` call, across every component), returning
+/// whatever was found so far alongside whether the search was cut short.
+/// Johnson's `blocked`/`unblock_map` bookkeeping already keeps a single
+/// circuit search from re-exploring a path through a node still blocked on
+/// it — this budget guards the case that still isn't enough: a
+/// strongly-connected component large or dense enough that even the
+/// non-redundant search visits more states than `max_visits` allows.
+pub fn enumerate_elementary_cycles_bounded(
+    graph: &LogicalGraph,
+    max_cycles: usize,
+    max_visits: usize,
+) -> (Vec<Vec<String>>, bool) {
+    let adj = graph.get_dependency_adjacency();
+    let mut cycles: Vec<Vec<String>> = Vec::new();
+    let mut budget = CycleSearchBudget { max_cycles, max_visits, visits: 0, overflowed: false };
+
+    for component in strongly_connected_components(&adj) {
+        if budget.overflowed {
+            break;
+        }
+        johnson_cycles_in_component(&adj, &component, &mut cycles, &mut budget);
+    }
+
+    (cycles, budget.overflowed)
+}
+
+/// Tracks `enumerate_elementary_cycles_bounded`'s two limits against a
+/// single search: `visits` counts `find_circuits` calls across every
+/// component, and `overflowed` latches permanently true the moment either
+/// limit is hit, so every still-running branch of the search stops at its
+/// next opportunity rather than only the one that tripped the limit.
+struct CycleSearchBudget {
+    max_cycles: usize,
+    max_visits: usize,
+    visits: usize,
+    overflowed: bool,
+}
+
+/// Partition `adj`'s nodes into strongly-connected components via a single
+/// run of Tarjan's algorithm, in deterministic (sorted) visitation order.
+///
+/// Shared with `topo_sort::topological_sort_condensed`, which condenses each
+/// component down to a single super-node before ordering.
+pub(crate) fn strongly_connected_components(adj: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut ids: Vec<&String> = adj.keys().collect();
+    ids.sort();
+
+    let mut tarjan = Tarjan {
+        adj,
+        index: HashMap::new(),
+        lowlink: HashMap::new(),
+        on_stack: HashSet::new(),
+        stack: Vec::new(),
+        next_index: 0,
+        components: Vec::new(),
+    };
+
+    for id in ids {
+        if !tarjan.index.contains_key(id) {
+            tarjan.visit(id);
+        }
+    }
+
+    tarjan.components
+}
+
+/// Johnson's algorithm restricted to a single strongly-connected component:
+/// peel off the least remaining node `s` as the start of a circuit search,
+/// find the (possibly smaller) strongly-connected component `s` belongs to
+/// once earlier nodes are removed, search it for cycles through `s`, then
+/// drop `s` and repeat until the component is exhausted.
+fn johnson_cycles_in_component(
+    adj: &HashMap<String, Vec<String>>,
+    component: &[String],
+    cycles: &mut Vec<Vec<String>>,
+    budget: &mut CycleSearchBudget,
+) {
+    let mut remaining: Vec<String> = component.to_vec();
+    remaining.sort();
+    let mut active: HashSet<String> = remaining.iter().cloned().collect();
+
+    while !remaining.is_empty() {
+        if budget.overflowed {
+            return;
+        }
+        let s = remaining.remove(0);
+        let sub_adj = restrict_adjacency(adj, &active);
+        let least_scc = strongly_connected_components(&sub_adj)
+            .into_iter()
+            .find(|c| c.contains(&s));
+
+        if let Some(scc) = least_scc {
+            let scc_nodes: HashSet<String> = scc.into_iter().collect();
+            let mut blocked: HashSet<String> = HashSet::new();
+            let mut unblock_map: HashMap<String, HashSet<String>> = HashMap::new();
+            let mut stack: Vec<String> = Vec::new();
+            find_circuits(&s, &s, &sub_adj, &scc_nodes, &mut blocked, &mut unblock_map, &mut stack, cycles, budget);
+        }
+
+        active.remove(&s);
+    }
+}
+
+/// Restrict an adjacency list to only the nodes in `active` and edges between them.
+fn restrict_adjacency(adj: &HashMap<String, Vec<String>>, active: &HashSet<String>) -> HashMap<String, Vec<String>> {
+    active
+        .iter()
+        .map(|node| {
+            let neighbors = adj
+                .get(node)
+                .map(|ns| ns.iter().filter(|n| active.contains(*n)).cloned().collect())
+                .unwrap_or_default();
+            (node.clone(), neighbors)
+        })
+        .collect()
+}
+
+/// The circuit-search half of Johnson's algorithm: DFS from `v` looking for a
+/// path back to `s` within `scc`, blocking each visited node so it isn't
+/// reused mid-search, and unblocking (via `unblock_map`) only once a circuit
+/// through it is actually found — otherwise a node stays blocked until one of
+/// its successors on this search is unblocked too. Returns whether any
+/// circuit through `v` was found, so callers further up the stack know
+/// whether to unblock themselves.
+#[allow(clippy::too_many_arguments)]
+fn find_circuits(
+    v: &str,
+    s: &str,
+    adj: &HashMap<String, Vec<String>>,
+    scc: &HashSet<String>,
+    blocked: &mut HashSet<String>,
+    unblock_map: &mut HashMap<String, HashSet<String>>,
+    stack: &mut Vec<String>,
+    cycles: &mut Vec<Vec<String>>,
+    budget: &mut CycleSearchBudget,
+) -> bool {
+    budget.visits += 1;
+    if budget.visits > budget.max_visits {
+        budget.overflowed = true;
+        return false;
+    }
+
+    let mut found = false;
+    blocked.insert(v.to_string());
+    stack.push(v.to_string());
+
+    let mut neighbors: Vec<&String> = adj.get(v).map(|ns| ns.iter().filter(|n| scc.contains(*n)).collect()).unwrap_or_default();
+    neighbors.sort();
+    neighbors.dedup();
+
+    for w in neighbors {
+        if budget.overflowed {
+            break;
+        }
+        if w == s {
+            cycles.push(stack.clone());
+            found = true;
+            if cycles.len() >= budget.max_cycles {
+                budget.overflowed = true;
+            }
+        } else if !blocked.contains(w) && find_circuits(w, s, adj, scc, blocked, unblock_map, stack, cycles, budget) {
+            found = true;
+        }
+    }
+
+    if found {
+        unblock(v, blocked, unblock_map);
+    } else if let Some(ns) = adj.get(v) {
+        for w in ns.iter().filter(|n| scc.contains(*n)) {
+            unblock_map.entry(w.clone()).or_default().insert(v.to_string());
+        }
+    }
+
+    stack.pop();
+    found
+}
+
+fn unblock(v: &str, blocked: &mut HashSet<String>, unblock_map: &mut HashMap<String, HashSet<String>>) {
+    blocked.remove(v);
+    if let Some(dependents) = unblock_map.get_mut(v) {
+        let dependents = std::mem::take(dependents);
+        for w in dependents {
+            if blocked.contains(&w) {
+                unblock(&w, blocked, unblock_map);
+            }
+        }
+    }
+}
+
+/// State for a single run of Tarjan's SCC algorithm over owned `String` node ids.
+struct Tarjan<'a> {
+    adj: &'a HashMap<String, Vec<String>>,
+    index: HashMap<String, usize>,
+    lowlink: HashMap<String, usize>,
+    on_stack: HashSet<String>,
+    stack: Vec<String>,
+    next_index: usize,
+    components: Vec<Vec<String>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn visit(&mut self, v: &str) {
+        self.index.insert(v.to_string(), self.next_index);
+        self.lowlink.insert(v.to_string(), self.next_index);
+        self.next_index += 1;
+        self.stack.push(v.to_string());
+        self.on_stack.insert(v.to_string());
+
+        if let Some(neighbors) = self.adj.get(v).cloned() {
+            for w in &neighbors {
+                if !self.index.contains_key(w) {
+                    self.visit(w);
+                    let w_low = self.lowlink[w];
+                    let v_low = self.lowlink[v];
+                    self.lowlink.insert(v.to_string(), v_low.min(w_low));
+                } else if self.on_stack.contains(w) {
+                    let w_index = self.index[w];
+                    let v_low = self.lowlink[v];
+                    self.lowlink.insert(v.to_string(), v_low.min(w_index));
+                }
+            }
+        }
+
+        if self.lowlink[v] == self.index[v] {
+            let mut component = Vec::new();
+            loop {
+                let w = self.stack.pop().expect("stack non-empty until v is popped");
+                self.on_stack.remove(&w);
+                let is_v = w == v;
+                component.push(w);
+                if is_v {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +514,197 @@ mod tests {
         let cycles = detect_cycles(&graph);
         assert_eq!(cycles.len(), 0, "contradicts edges should not form cycles");
     }
+
+    #[test]
+    fn test_find_circular_reasoning_triangle() {
+        // A → B → C → A
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+                make_rel("r3", "C", "A", "supports"),
+            ],
+        );
+        let clusters = find_circular_reasoning(&graph);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].proposition_ids.len(), 3);
+    }
+
+    #[test]
+    fn test_find_circular_reasoning_self_loop() {
+        let graph = make_graph(
+            vec![make_prop("A", "claim", "high")],
+            vec![make_rel("r1", "A", "A", "depends_on")],
+        );
+        let clusters = find_circular_reasoning(&graph);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].proposition_ids, vec!["A".to_string()]);
+    }
+
+    #[test]
+    fn test_find_circular_reasoning_acyclic_graph_empty() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "evidence", "high"),
+                make_prop("B", "claim", "high"),
+            ],
+            vec![make_rel("r1", "A", "B", "supports")],
+        );
+        let clusters = find_circular_reasoning(&graph);
+        assert!(clusters.is_empty(), "Acyclic graph should have no circular clusters");
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_triangle() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+                make_rel("r3", "C", "A", "supports"),
+            ],
+        );
+        let cycles = enumerate_elementary_cycles(&graph);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), 3);
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_self_loop() {
+        let graph = make_graph(
+            vec![make_prop("A", "claim", "high")],
+            vec![make_rel("r1", "A", "A", "depends_on")],
+        );
+        let cycles = enumerate_elementary_cycles(&graph);
+        assert_eq!(cycles, vec![vec!["A".to_string()]]);
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_acyclic_graph_empty() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "evidence", "high"),
+                make_prop("B", "claim", "high"),
+            ],
+            vec![make_rel("r1", "A", "B", "supports")],
+        );
+        assert!(enumerate_elementary_cycles(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_finds_distinct_loops_sharing_a_component() {
+        // A bowtie: A → B → A (loop 1) and B → C → B (loop 2), sharing node B,
+        // all within one SCC, which find_circular_reasoning reports as a single
+        // cluster; Johnson's algorithm must report each loop separately.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+                make_rel("r3", "B", "C", "supports"),
+                make_rel("r4", "C", "B", "supports"),
+            ],
+        );
+
+        let components = strongly_connected_components(&graph.get_dependency_adjacency());
+        assert_eq!(components.len(), 1, "A, B, C should form a single SCC");
+
+        let mut cycles = enumerate_elementary_cycles(&graph);
+        cycles.sort_by_key(|c| c.len());
+        assert_eq!(cycles.len(), 2, "Expected the two distinct 2-node loops, got {:?}", cycles);
+        assert_eq!(cycles[0].len(), 2);
+        assert_eq!(cycles[1].len(), 2);
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_two_separate_cycles() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "claim", "high"),
+                make_prop("D", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+                make_rel("r3", "C", "D", "depends_on"),
+                make_rel("r4", "D", "C", "depends_on"),
+            ],
+        );
+        let cycles = enumerate_elementary_cycles(&graph);
+        assert_eq!(cycles.len(), 2, "Should detect two separate cycles");
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_bounded_under_budget_matches_exact() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+                make_rel("r3", "C", "A", "supports"),
+            ],
+        );
+        let (cycles, overflowed) = enumerate_elementary_cycles_bounded(&graph, 500, 200_000);
+        assert!(!overflowed);
+        assert_eq!(cycles.len(), 1);
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_bounded_max_cycles_reports_overflow() {
+        // A bowtie with two distinct 2-node loops sharing node B — capping
+        // max_cycles at 1 should stop after the first and flag overflow.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+                make_rel("r3", "B", "C", "supports"),
+                make_rel("r4", "C", "B", "supports"),
+            ],
+        );
+        let (cycles, overflowed) = enumerate_elementary_cycles_bounded(&graph, 1, 200_000);
+        assert!(overflowed);
+        assert_eq!(cycles.len(), 1);
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_bounded_max_visits_reports_overflow() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+                make_rel("r3", "C", "A", "supports"),
+            ],
+        );
+        let (_, overflowed) = enumerate_elementary_cycles_bounded(&graph, 500, 0);
+        assert!(overflowed);
+    }
 }
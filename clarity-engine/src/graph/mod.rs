@@ -0,0 +1,4 @@
+pub mod centrality;
+pub mod community_detection;
+pub mod cycle_detection;
+pub mod topo_sort;
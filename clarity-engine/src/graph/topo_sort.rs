@@ -1,58 +1,152 @@
+use crate::graph::cycle_detection::strongly_connected_components;
 use crate::types::LogicalGraph;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
-/// Topological sort using Kahn's algorithm.
+/// Topological sort, via a thin wrapper around [`IncrementalTopo`]: register
+/// every proposition, feed it every dependency edge in order, and drain its
+/// order.
 ///
 /// Orders propositions by logical dependency (most fundamental first).
 /// Only considers dependency edges ("supports", "depends_on", "assumes").
 ///
-/// If cycles exist, the cyclic nodes are omitted — only acyclic nodes
-/// appear in the result, in correct dependency order.
+/// If cycles exist, the cyclic nodes are omitted, along with anything that
+/// depends on them (directly or transitively) — only nodes whose whole
+/// dependency chain is acyclic appear in the result, in correct dependency
+/// order. Cyclic clusters are found via the same strongly-connected-component
+/// partition `topological_sort_condensed` uses, since `IncrementalTopo` only
+/// rejects one cycle-closing edge at a time and can't itself identify a
+/// cluster's full membership or the nodes forward of it.
 pub fn topological_sort(graph: &LogicalGraph) -> Vec<String> {
     let adj = graph.get_dependency_adjacency();
 
-    // Compute in-degree for each node (only from dependency edges)
-    let mut in_degree: HashMap<&str, usize> = HashMap::new();
-    for p in &graph.propositions {
-        in_degree.entry(p.id.as_str()).or_insert(0);
+    let excluded = nodes_downstream_of_cycles(&adj);
+
+    let mut ids: Vec<&str> = graph.propositions.iter().map(|p| p.id.as_str()).collect();
+    ids.sort();
+
+    let mut topo = IncrementalTopo::new();
+    for id in &ids {
+        if !excluded.contains(*id) {
+            topo.add_proposition(id);
+        }
     }
-    for rel in &graph.relationships {
-        if crate::types::is_dependency_edge(&rel.rel_type) {
-            *in_degree.entry(rel.to_id.as_str()).or_insert(0) += 1;
+
+    let mut edges: Vec<(&str, &str)> = graph
+        .relationships
+        .iter()
+        .filter(|rel| crate::types::is_dependency_edge(&rel.rel_type))
+        .map(|rel| (rel.from_id.as_str(), rel.to_id.as_str()))
+        .collect();
+    edges.sort();
+    for (from, to) in edges {
+        if excluded.contains(from) || excluded.contains(to) {
+            continue;
         }
+        // The induced subgraph over non-excluded nodes is acyclic by
+        // construction, so this can never reject an edge as cycle-closing.
+        topo.add_dependency(from, to).expect("non-excluded nodes form an acyclic subgraph");
     }
 
-    // Initialize queue with all zero-in-degree nodes
-    let mut queue: VecDeque<String> = VecDeque::new();
-    for (&node, &deg) in &in_degree {
-        if deg == 0 {
-            queue.push_back(node.to_string());
+    topo.iter_order()
+}
+
+/// Every node that is part of a dependency cycle (a strongly-connected
+/// component with more than one member, or a single node with a self-edge),
+/// plus everything forward-reachable from one — the same set Kahn's
+/// algorithm implicitly excludes, since a node downstream of a cycle never
+/// reaches in-degree zero.
+fn nodes_downstream_of_cycles(adj: &HashMap<String, Vec<String>>) -> HashSet<String> {
+    let components = strongly_connected_components(adj);
+
+    let mut excluded: HashSet<String> = HashSet::new();
+    for component in &components {
+        let is_cyclic = component.len() > 1
+            || component.first().is_some_and(|n| adj.get(n).is_some_and(|ns| ns.contains(n)));
+        if !is_cyclic {
+            continue;
+        }
+        let mut stack = component.clone();
+        while let Some(node) = stack.pop() {
+            if excluded.insert(node.clone()) {
+                if let Some(next) = adj.get(&node) {
+                    stack.extend(next.iter().cloned());
+                }
+            }
         }
     }
+    excluded
+}
 
-    // Sort the initial queue for deterministic output
-    let mut initial: Vec<String> = queue.drain(..).collect();
-    initial.sort();
-    for n in initial {
-        queue.push_back(n);
+/// Topologically order the graph even in the presence of cycles, by first
+/// collapsing each strongly-connected component (found via the same Tarjan
+/// partition `cycle_detection` uses) into a single super-node — the
+/// "condensation" of the dependency graph, which is always acyclic — then
+/// running Kahn's algorithm over the condensation and expanding each
+/// super-node back into its members.
+///
+/// Unlike `topological_sort`, no proposition is ever dropped: a singleton
+/// entry is an ordinary acyclic proposition, and a multi-member entry is a
+/// cyclic cluster (its members sorted for determinism) placed wherever the
+/// condensation places it relative to everything else. Only dependency
+/// edges ("supports", "depends_on", "assumes") are considered, same as
+/// `topological_sort`.
+pub fn topological_sort_condensed(graph: &LogicalGraph) -> Vec<Vec<String>> {
+    let adj = graph.get_dependency_adjacency();
+    let components = strongly_connected_components(&adj);
+
+    // Assign each node its component index, and give each component a
+    // sorted member list both for deterministic output and as a tie-break key.
+    let mut node_component: HashMap<String, usize> = HashMap::new();
+    let mut members: Vec<Vec<String>> = Vec::with_capacity(components.len());
+    for (idx, component) in components.into_iter().enumerate() {
+        let mut sorted_component = component;
+        sorted_component.sort();
+        for node in &sorted_component {
+            node_component.insert(node.clone(), idx);
+        }
+        members.push(sorted_component);
     }
 
-    let mut result: Vec<String> = Vec::new();
+    // Condense: an edge survives only between distinct components, deduplicated.
+    let mut condensed_edges: HashSet<(usize, usize)> = HashSet::new();
+    for (from, tos) in &adj {
+        let from_comp = node_component[from];
+        for to in tos {
+            let to_comp = node_component[to];
+            if from_comp != to_comp {
+                condensed_edges.insert((from_comp, to_comp));
+            }
+        }
+    }
 
-    while let Some(node) = queue.pop_front() {
-        result.push(node.clone());
+    let mut condensed_adj: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut in_degree: HashMap<usize, usize> = HashMap::new();
+    for idx in 0..members.len() {
+        in_degree.entry(idx).or_insert(0);
+    }
+    for &(from, to) in &condensed_edges {
+        condensed_adj.entry(from).or_default().push(to);
+        *in_degree.entry(to).or_insert(0) += 1;
+    }
 
-        // Reduce in-degree of all neighbors
-        if let Some(neighbors) = adj.get(&node) {
-            // Sort neighbors for deterministic output
+    // Kahn's algorithm over the (always-acyclic) condensation, breaking ties
+    // by each component's sorted member list for deterministic output.
+    let mut ready: Vec<usize> = in_degree.iter().filter(|&(_, &deg)| deg == 0).map(|(&idx, _)| idx).collect();
+    ready.sort_by(|a, b| members[*a].cmp(&members[*b]));
+    let mut queue: VecDeque<usize> = ready.into_iter().collect();
+
+    let mut result: Vec<Vec<String>> = Vec::new();
+    while let Some(idx) = queue.pop_front() {
+        result.push(members[idx].clone());
+
+        if let Some(neighbors) = condensed_adj.get(&idx) {
             let mut sorted_neighbors = neighbors.clone();
-            sorted_neighbors.sort();
-            for neighbor in &sorted_neighbors {
-                if let Some(deg) = in_degree.get_mut(neighbor.as_str()) {
-                    *deg -= 1;
-                    if *deg == 0 {
-                        queue.push_back(neighbor.clone());
-                    }
+            sorted_neighbors.sort_by(|a, b| members[*a].cmp(&members[*b]));
+            for neighbor in sorted_neighbors {
+                let deg = in_degree.get_mut(&neighbor).expect("every component has an in-degree entry");
+                *deg -= 1;
+                if *deg == 0 {
+                    queue.push_back(neighbor);
                 }
             }
         }
@@ -61,6 +155,144 @@ pub fn topological_sort(graph: &LogicalGraph) -> Vec<String> {
     result
 }
 
+/// Maintains a dense topological order that updates online, via the
+/// Pearce–Kelly algorithm, as propositions and dependency edges are added
+/// one at a time — useful for interactive graph editing, where recomputing
+/// [`topological_sort`] from scratch after every keystroke would be wasteful.
+///
+/// `ord` gives each known node a position in the current order. Inserting an
+/// edge `u → v` that already respects the order (`ord[u] < ord[v]`) leaves
+/// everything untouched; otherwise only the "affected region" between the
+/// two endpoints is reassigned, not the whole graph — see [`Self::add_dependency`].
+#[derive(Debug, Default)]
+pub struct IncrementalTopo {
+    ord: HashMap<String, i64>,
+    forward: HashMap<String, Vec<String>>,
+    backward: HashMap<String, Vec<String>>,
+    next_ord: i64,
+}
+
+impl IncrementalTopo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a node at the end of the current order. A no-op if the node
+    /// is already known.
+    pub fn add_proposition(&mut self, id: &str) {
+        if self.ord.contains_key(id) {
+            return;
+        }
+        self.ord.insert(id.to_string(), self.next_ord);
+        self.next_ord += 1;
+        self.forward.entry(id.to_string()).or_default();
+        self.backward.entry(id.to_string()).or_default();
+    }
+
+    /// Insert a dependency edge `from → to`, keeping the order valid.
+    ///
+    /// If `ord[from] < ord[to]` already, the new edge doesn't violate the
+    /// current order and nothing else changes. Otherwise the affected
+    /// region is found by a forward DFS from `to` (nodes reachable from `to`
+    /// that currently sort at or before `from`) and a backward DFS from
+    /// `from` (nodes that reach `from` and currently sort at or after `to`);
+    /// those are the only nodes whose order could be wrong once the edge is
+    /// added, so only their order values are reassigned — interleaved back
+    /// to back so the backward-affected nodes still sort before the
+    /// forward-affected ones. If the forward DFS reaches `from` itself, the
+    /// edge would close a cycle and is rejected.
+    pub fn add_dependency(&mut self, from: &str, to: &str) -> Result<(), String> {
+        if !self.ord.contains_key(from) || !self.ord.contains_key(to) {
+            return Err(format!("unknown proposition in dependency {} -> {}", from, to));
+        }
+        if from == to {
+            return Err(format!("{} -> {} would be a self-dependency cycle", from, to));
+        }
+
+        self.forward.entry(from.to_string()).or_default().push(to.to_string());
+        self.backward.entry(to.to_string()).or_default().push(from.to_string());
+
+        let ord_from = self.ord[from];
+        let ord_to = self.ord[to];
+        if ord_from < ord_to {
+            return Ok(());
+        }
+
+        let mut forward_affected: Vec<String> = Vec::new();
+        let mut visited_forward: HashSet<String> = HashSet::new();
+        let mut stack = vec![to.to_string()];
+        while let Some(node) = stack.pop() {
+            if !visited_forward.insert(node.clone()) {
+                continue;
+            }
+            if node == from {
+                // The new edge closes a cycle — undo it and reject.
+                self.forward.get_mut(from).expect("just inserted").pop();
+                self.backward.get_mut(to).expect("just inserted").pop();
+                return Err(format!("{} -> {} would create a cycle", from, to));
+            }
+            if self.ord[&node] <= ord_from {
+                forward_affected.push(node.clone());
+            }
+            for next in self.forward.get(&node).cloned().unwrap_or_default() {
+                if !visited_forward.contains(&next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut backward_affected: Vec<String> = Vec::new();
+        let mut visited_backward: HashSet<String> = HashSet::new();
+        let mut stack = vec![from.to_string()];
+        while let Some(node) = stack.pop() {
+            if !visited_backward.insert(node.clone()) {
+                continue;
+            }
+            if self.ord[&node] >= ord_to {
+                backward_affected.push(node.clone());
+            }
+            for prev in self.backward.get(&node).cloned().unwrap_or_default() {
+                if !visited_backward.contains(&prev) {
+                    stack.push(prev);
+                }
+            }
+        }
+
+        // Pour the affected nodes back into the same set of order values
+        // they already occupied, with backward-affected nodes (in their old
+        // relative order) placed ahead of forward-affected nodes (likewise).
+        backward_affected.sort_by_key(|n| self.ord[n]);
+        forward_affected.sort_by_key(|n| self.ord[n]);
+        let mut slots: Vec<i64> =
+            backward_affected.iter().chain(forward_affected.iter()).map(|n| self.ord[n]).collect();
+        slots.sort();
+
+        for (node, slot) in backward_affected.into_iter().chain(forward_affected).zip(slots) {
+            self.ord.insert(node, slot);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a dependency edge. Removing an edge can never turn a valid
+    /// order invalid, so no order values need reassigning.
+    pub fn remove_dependency(&mut self, from: &str, to: &str) {
+        if let Some(succs) = self.forward.get_mut(from) {
+            succs.retain(|n| n != to);
+        }
+        if let Some(preds) = self.backward.get_mut(to) {
+            preds.retain(|n| n != from);
+        }
+    }
+
+    /// The known nodes, in their current topological order.
+    pub fn iter_order(&self) -> Vec<String> {
+        let mut nodes: Vec<String> = self.ord.keys().cloned().collect();
+        nodes.sort_by_key(|n| self.ord[n]);
+        nodes
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,6 +372,27 @@ mod tests {
         assert_eq!(order.len(), 1, "Only non-cyclic nodes should appear");
     }
 
+    #[test]
+    fn test_cycle_excludes_downstream_dependents_too() {
+        // A → B → A (cycle), cycle → D (D depends on the cycle). D's
+        // in-degree is satisfied only by a cyclic node, so it never reaches
+        // zero and must be excluded along with A and B.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("D", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+                make_rel("r3", "B", "D", "supports"),
+            ],
+        );
+        let order = topological_sort(&graph);
+        assert!(order.is_empty(), "A, B, and D should all be excluded: {:?}", order);
+    }
+
     #[test]
     fn test_ignores_non_dependency_edges() {
         // A contradicts B — should NOT create a dependency
@@ -153,4 +406,156 @@ mod tests {
         let order = topological_sort(&graph);
         assert_eq!(order.len(), 2, "Both nodes should appear — contradicts is not a dependency");
     }
+
+    #[test]
+    fn test_condensed_linear_chain_matches_plain_sort() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "evidence", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "C", "supports"),
+            ],
+        );
+        let order = topological_sort_condensed(&graph);
+        assert_eq!(order, vec![vec!["A".to_string()], vec!["B".to_string()], vec!["C".to_string()]]);
+    }
+
+    #[test]
+    fn test_condensed_keeps_cyclic_nodes_instead_of_dropping_them() {
+        // A → B → A (cycle), C independent — unlike topological_sort, A and B
+        // must still appear, grouped together as one cyclic cluster.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("C", "evidence", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+            ],
+        );
+        let order = topological_sort_condensed(&graph);
+        assert_eq!(order.len(), 2, "The cyclic cluster and C should each be one entry");
+        let cluster = order.iter().find(|group| group.len() == 2).expect("cyclic cluster should be present");
+        assert_eq!(cluster, &vec!["A".to_string(), "B".to_string()]);
+        assert!(order.contains(&vec!["C".to_string()]));
+    }
+
+    #[test]
+    fn test_condensed_orders_cyclic_cluster_relative_to_dependents() {
+        // A → B → A (cycle), cycle → D (D depends on the cycle, so the
+        // cluster must be placed before D in the condensation order).
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "evidence", "high"),
+                make_prop("D", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+                make_rel("r3", "B", "D", "supports"),
+            ],
+        );
+        let order = topological_sort_condensed(&graph);
+        assert_eq!(order.len(), 2);
+        let cluster_pos = order.iter().position(|group| group.len() == 2).unwrap();
+        let d_pos = order.iter().position(|group| group == &vec!["D".to_string()]).unwrap();
+        assert!(cluster_pos < d_pos, "The cyclic cluster should come before the claim that depends on it");
+    }
+
+    #[test]
+    fn test_condensed_ignores_non_dependency_edges() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "claim", "high"),
+            ],
+            vec![make_rel("r1", "A", "B", "contradicts")],
+        );
+        let order = topological_sort_condensed(&graph);
+        assert_eq!(order.len(), 2, "Both nodes should appear as independent singletons");
+    }
+
+    #[test]
+    fn test_incremental_topo_preserves_insertion_order_with_no_edges() {
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("A");
+        topo.add_proposition("B");
+        topo.add_proposition("C");
+        assert_eq!(topo.iter_order(), vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn test_incremental_topo_edge_already_in_order_is_a_no_op() {
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("A");
+        topo.add_proposition("B");
+        topo.add_dependency("A", "B").unwrap();
+        assert_eq!(topo.iter_order(), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_incremental_topo_out_of_order_edge_reorders_affected_region() {
+        // Inserted B, A (in that order), then the dependency A -> B forces A
+        // ahead of B even though A was registered second.
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("B");
+        topo.add_proposition("A");
+        topo.add_dependency("A", "B").unwrap();
+        let order = topo.iter_order();
+        let pos_a = order.iter().position(|x| x == "A").unwrap();
+        let pos_b = order.iter().position(|x| x == "B").unwrap();
+        assert!(pos_a < pos_b, "A should now sort before B: {:?}", order);
+    }
+
+    #[test]
+    fn test_incremental_topo_rejects_cycle() {
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("A");
+        topo.add_proposition("B");
+        topo.add_dependency("A", "B").unwrap();
+        let result = topo.add_dependency("B", "A");
+        assert!(result.is_err());
+        // The rejected edge must not have mutated the order.
+        assert_eq!(topo.iter_order(), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_incremental_topo_reorders_a_longer_chain() {
+        // D, C, B, A registered in that order; dependencies A->B->C->D force
+        // the final order to be exactly A, B, C, D.
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("D");
+        topo.add_proposition("C");
+        topo.add_proposition("B");
+        topo.add_proposition("A");
+        topo.add_dependency("A", "B").unwrap();
+        topo.add_dependency("B", "C").unwrap();
+        topo.add_dependency("C", "D").unwrap();
+        assert_eq!(topo.iter_order(), vec!["A", "B", "C", "D"]);
+    }
+
+    #[test]
+    fn test_incremental_topo_remove_dependency_does_not_touch_order() {
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("B");
+        topo.add_proposition("A");
+        topo.add_dependency("A", "B").unwrap();
+        let before = topo.iter_order();
+        topo.remove_dependency("A", "B");
+        assert_eq!(topo.iter_order(), before);
+    }
+
+    #[test]
+    fn test_incremental_topo_unknown_proposition_is_an_error() {
+        let mut topo = IncrementalTopo::new();
+        topo.add_proposition("A");
+        assert!(topo.add_dependency("A", "B").is_err());
+    }
 }
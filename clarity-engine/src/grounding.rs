@@ -0,0 +1,233 @@
+use crate::types::{GroundingGap, LogicalGraph, SuggestedEdge};
+use std::collections::{HashMap, HashSet};
+
+/// For each claim, search backward along dependency edges (`supports`,
+/// `depends_on`, `assumes`) for at least one path terminating in an
+/// `evidence` proposition with no unmet dependencies of its own — an axiom.
+/// A claim with no such path gets a `GroundingGap`: the blame frontier (the
+/// closest ungrounded predecessors where the chain actually breaks, not just
+/// the claim itself) and, when the graph has grounded evidence to point to,
+/// a minimal set of `supports` edges that would close the gap.
+///
+/// This is the constructive counterpart to `fallacy_detector`'s purely
+/// negative pattern matching: instead of just flagging a structural smell,
+/// it tells the user exactly which propositions need evidence and what
+/// evidence is already on hand to supply it.
+pub fn validate_grounding(graph: &LogicalGraph) -> Vec<GroundingGap> {
+    let mut memo: HashMap<String, bool> = HashMap::new();
+    let mut gaps = Vec::new();
+
+    let mut claim_ids: Vec<String> = graph.get_propositions_by_type("claim").iter().map(|p| p.id.clone()).collect();
+    claim_ids.sort();
+
+    for claim_id in claim_ids {
+        if is_grounded(graph, &claim_id, &mut memo, &mut HashSet::new()) {
+            continue;
+        }
+
+        let blame_frontier = find_blame_frontier(graph, &claim_id, &mut memo);
+        let suggested_supports = suggest_supports(graph, &blame_frontier, &mut memo);
+        gaps.push(GroundingGap { claim_id, blame_frontier, suggested_supports });
+    }
+
+    gaps
+}
+
+/// Whether `id` has a path back to an axiom via dependency edges, cached in
+/// `memo` so shared ancestors aren't re-walked for every claim. `visiting`
+/// guards against infinite recursion around a dependency cycle — a node
+/// still being explored when revisited is provisionally "not grounded (yet)",
+/// so a `false` that rests on such a revisit is only cached by the outermost
+/// call: the revisited node may still turn out grounded via another path.
+fn is_grounded(graph: &LogicalGraph, id: &str, memo: &mut HashMap<String, bool>, visiting: &mut HashSet<String>) -> bool {
+    if let Some(&grounded) = memo.get(id) {
+        return grounded;
+    }
+    if !visiting.insert(id.to_string()) {
+        return false;
+    }
+
+    let incoming: Vec<String> = graph
+        .get_relationships_to(id)
+        .iter()
+        .filter(|r| crate::types::is_dependency_edge(&r.rel_type))
+        .map(|r| r.from_id.clone())
+        .collect();
+
+    let grounded = if incoming.is_empty() {
+        graph.get_proposition(id).map(|p| p.prop_type == "evidence").unwrap_or(false)
+    } else {
+        incoming.iter().any(|pred| is_grounded(graph, pred, memo, visiting))
+    };
+
+    visiting.remove(id);
+    if grounded || visiting.is_empty() || incoming.iter().all(|pred| memo.contains_key(pred)) {
+        memo.insert(id.to_string(), grounded);
+    }
+    grounded
+}
+
+/// DFS backward from `claim_id` through ungrounded predecessors only -- a
+/// grounded predecessor already explains that branch, so it's not blame --
+/// collecting the nodes where the backward search actually dead-ends:
+/// either a node with no incoming dependency edge at all (nothing behind
+/// it), or a node whose only ungrounded predecessors loop back onto the
+/// current search path (a dependency cycle with no independent grounding).
+/// Those are the root causes a user would need to supply evidence for.
+fn find_blame_frontier(graph: &LogicalGraph, claim_id: &str, memo: &mut HashMap<String, bool>) -> Vec<String> {
+    let mut frontier: Vec<String> = Vec::new();
+    let mut frontier_set: HashSet<String> = HashSet::new();
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut path: HashSet<String> = HashSet::new();
+
+    blame_dfs(graph, claim_id, memo, &mut visited, &mut path, &mut frontier_set, &mut frontier);
+
+    frontier.sort();
+    frontier
+}
+
+#[allow(clippy::too_many_arguments)]
+fn blame_dfs(
+    graph: &LogicalGraph,
+    id: &str,
+    memo: &mut HashMap<String, bool>,
+    visited: &mut HashSet<String>,
+    path: &mut HashSet<String>,
+    frontier_set: &mut HashSet<String>,
+    frontier: &mut Vec<String>,
+) {
+    if visited.contains(id) {
+        return;
+    }
+    path.insert(id.to_string());
+
+    let incoming: Vec<String> = graph
+        .get_relationships_to(id)
+        .iter()
+        .filter(|r| crate::types::is_dependency_edge(&r.rel_type))
+        .map(|r| r.from_id.clone())
+        .collect();
+
+    let ungrounded_predecessors: Vec<String> =
+        incoming.into_iter().filter(|pred| !is_grounded(graph, pred, memo, &mut HashSet::new())).collect();
+
+    let explored_a_predecessor = ungrounded_predecessors.iter().any(|pred| !path.contains(pred));
+    for pred in &ungrounded_predecessors {
+        if !path.contains(pred) && !visited.contains(pred) {
+            blame_dfs(graph, pred, memo, visited, path, frontier_set, frontier);
+        }
+    }
+
+    if !explored_a_predecessor && frontier_set.insert(id.to_string()) {
+        frontier.push(id.to_string());
+    }
+
+    path.remove(id);
+    visited.insert(id.to_string());
+}
+
+/// Suggest closing every frontier gap with a `supports` edge from the same
+/// existing grounded evidence node — the lexicographically first one, purely
+/// for determinism — so the suggestion is minimal (one new source) rather
+/// than inventing a distinct piece of evidence per gap. Empty when the graph
+/// has no grounded evidence to point to.
+fn suggest_supports(graph: &LogicalGraph, frontier: &[String], memo: &mut HashMap<String, bool>) -> Vec<SuggestedEdge> {
+    let mut grounded_evidence: Vec<String> = graph
+        .get_propositions_by_type("evidence")
+        .iter()
+        .filter(|p| is_grounded(graph, &p.id, memo, &mut HashSet::new()))
+        .map(|p| p.id.clone())
+        .collect();
+    grounded_evidence.sort();
+
+    let Some(evidence_id) = grounded_evidence.into_iter().next() else {
+        return Vec::new();
+    };
+
+    frontier.iter().map(|node| SuggestedEdge { from_id: evidence_id.clone(), to_id: node.clone() }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_claim_grounded_by_evidence_has_no_gap() {
+        let graph = make_graph(
+            vec![make_prop("E", "evidence", "high"), make_prop("C", "claim", "high")],
+            vec![make_rel("r1", "E", "C", "supports")],
+        );
+        assert!(validate_grounding(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_claim_is_its_own_blame_frontier() {
+        let graph = make_graph(vec![make_prop("C", "claim", "high")], vec![]);
+        let gaps = validate_grounding(&graph);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].claim_id, "C");
+        assert_eq!(gaps[0].blame_frontier, vec!["C".to_string()]);
+    }
+
+    #[test]
+    fn test_blame_frontier_is_the_break_not_the_claim() {
+        // C depends on A (a constraint, not itself a claim under test), A
+        // depends on B (an unsupported assumption) — the chain breaks at B,
+        // two hops back from C.
+        let graph = make_graph(
+            vec![
+                make_prop("C", "claim", "high"),
+                make_prop("A", "constraint", "high"),
+                make_prop("B", "assumption", "medium"),
+            ],
+            vec![
+                make_rel("r1", "A", "C", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+            ],
+        );
+        let gaps = validate_grounding(&graph);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].claim_id, "C");
+        assert_eq!(gaps[0].blame_frontier, vec!["B".to_string()]);
+    }
+
+    #[test]
+    fn test_suggests_supports_from_existing_grounded_evidence() {
+        let graph = make_graph(
+            vec![
+                make_prop("E", "evidence", "high"),
+                make_prop("C1", "claim", "high"),
+                make_prop("C2", "claim", "high"),
+            ],
+            vec![make_rel("r1", "E", "C1", "supports")],
+        );
+        let gaps = validate_grounding(&graph);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].claim_id, "C2");
+        assert_eq!(gaps[0].suggested_supports, vec![SuggestedEdge { from_id: "E".to_string(), to_id: "C2".to_string() }]);
+    }
+
+    #[test]
+    fn test_no_suggestion_when_no_grounded_evidence_exists() {
+        let graph = make_graph(vec![make_prop("C", "claim", "high")], vec![]);
+        let gaps = validate_grounding(&graph);
+        assert_eq!(gaps.len(), 1);
+        assert!(gaps[0].suggested_supports.is_empty());
+    }
+
+    #[test]
+    fn test_cyclic_support_with_no_axiom_is_ungrounded() {
+        // A and B support each other in a loop, with no independent evidence
+        // behind either — circular support, not genuine grounding.
+        let graph = make_graph(
+            vec![make_prop("A", "claim", "high"), make_prop("B", "claim", "high")],
+            vec![
+                make_rel("r1", "A", "B", "supports"),
+                make_rel("r2", "B", "A", "supports"),
+            ],
+        );
+        let gaps = validate_grounding(&graph);
+        assert_eq!(gaps.len(), 2);
+    }
+}
@@ -3,7 +3,17 @@ mod sat_solver;
 mod fallacy_detector;
 mod bias_detector;
 mod argument_scorer;
+mod equivocation_detector;
+mod entailment_evaluator;
+mod temporal_algebra;
+mod counterfactual;
+mod truth_propagation;
+mod grounding;
+mod aba;
+mod reconstruction;
+mod pipeline;
 pub mod graph;
+pub mod tptp_export;
 
 use wasm_bindgen::prelude::*;
 
@@ -21,22 +31,69 @@ pub fn analyze_native(graph_json: &str) -> Result<String, String> {
     let graph: types::LogicalGraph = serde_json::from_str(graph_json)
         .map_err(|e| format!("Parse error: {}", e))?;
 
-    let contradictions = sat_solver::detect_contradictions(&graph);
-    let cycles = graph::cycle_detection::detect_cycles(&graph);
-    let topo_order = graph::topo_sort::topological_sort(&graph);
-    let centrality = graph::centrality::betweenness_centrality(&graph);
-    let scores = argument_scorer::score_arguments(&graph, &contradictions, &centrality);
-    let fallacies = fallacy_detector::detect_fallacies(&graph, &cycles);
-    let biases = bias_detector::detect_biases(&graph, &centrality);
-
-    let result = types::AnalysisResult {
-        contradictions,
-        fallacies,
-        biases,
-        argument_scores: scores,
-        cycles,
-        topological_order: topo_order,
+    let (result, _reused_stages) = pipeline::analyze(graph);
+
+    serde_json::to_string(&result).map_err(|e| format!("Serialize error: {}", e))
+}
+
+/// Like `analyze`, but backed by `pipeline`'s content-hash stage cache:
+/// re-analyzing a graph whose content is byte-identical (via JSON) to one
+/// already analyzed in this process reuses every cached stage instead of
+/// recomputing it. Takes the same whole-graph JSON as `analyze` — this
+/// crate has no diff/patch format for a `LogicalGraph`, so rather than
+/// invent one, incrementality is achieved by hashing the graph (and each
+/// stage's dependencies) the caller already sends, the same way `analyze`
+/// does internally.
+#[wasm_bindgen]
+pub fn analyze_incremental(graph_json: &str) -> Result<String, JsValue> {
+    analyze_incremental_native(graph_json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Native entry point for testing (no JsValue dependency).
+/// Same logic as `analyze_incremental` but returns `Result<String, String>`.
+pub fn analyze_incremental_native(graph_json: &str) -> Result<String, String> {
+    let graph: types::LogicalGraph = serde_json::from_str(graph_json)
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    let (result, reused_stages) = pipeline::analyze(graph);
+    let incremental_result = types::IncrementalAnalysisResult {
+        result,
+        reused_stages: reused_stages.into_iter().map(|s| s.to_string()).collect(),
     };
 
+    serde_json::to_string(&incremental_result).map_err(|e| format!("Serialize error: {}", e))
+}
+
+/// Reconstruct a `LogicalGraph` from an explicit reason/conclusion
+/// decomposition (JSON-encoded `types::ReconstructionInput`) — called from
+/// JavaScript via WASM. The resulting graph's JSON can be fed straight into
+/// `analyze`.
+#[wasm_bindgen]
+pub fn reconstruct_from_reasons(input_json: &str) -> Result<String, JsValue> {
+    reconstruct_from_reasons_native(input_json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Native entry point for testing (no JsValue dependency).
+/// Same logic as `reconstruct_from_reasons` but returns `Result<String, String>`.
+pub fn reconstruct_from_reasons_native(input_json: &str) -> Result<String, String> {
+    let input: types::ReconstructionInput =
+        serde_json::from_str(input_json).map_err(|e| format!("Parse error: {}", e))?;
+    let result = reconstruction::build_from_reasons(&input);
     serde_json::to_string(&result).map_err(|e| format!("Serialize error: {}", e))
 }
+
+/// Reconstruct a `LogicalGraph` from argdown-style indented-list text —
+/// called from JavaScript via WASM. The resulting graph's JSON can be fed
+/// straight into `analyze`.
+#[wasm_bindgen]
+pub fn reconstruct_argdown(text: &str) -> Result<String, JsValue> {
+    Ok(reconstruct_argdown_native(text))
+}
+
+/// Native entry point for testing (no JsValue dependency).
+/// Same logic as `reconstruct_argdown` but infallible — there's no parse
+/// error to report; malformed lines become warnings on the result itself.
+pub fn reconstruct_argdown_native(text: &str) -> String {
+    let result = reconstruction::parse_argdown(text);
+    serde_json::to_string(&result).unwrap_or_else(|e| format!("{{\"error\":\"Serialize error: {}\"}}", e))
+}
@@ -0,0 +1,608 @@
+use crate::types::{
+    AbaExtensions, AnalysisOverflow, AnalysisResult, ArgumentScore, CognitiveBias, Community,
+    Contradiction, Equivocation, EvidenceStrength, Fallacy, GroundingGap, LogicalGraph,
+    PropositionStatus, Question, ReasoningCycle, RetractionSuggestion, SearchBudget,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Max number of stage results a [`StageCache`] holds at once. Bounds memory
+/// in a long-lived process (e.g. the wasm build, analyzing many distinct
+/// graphs over the life of a browser tab) — once exceeded, the
+/// least-recently-used entry is evicted to make room for the new one.
+const STAGE_CACHE_CAPACITY: usize = 256;
+
+/// A cache of serialized stage outputs, keyed by `(stage name, content hash
+/// of that stage's dependency subset)`. `analyze_native` and
+/// `analyze_incremental` both read and write through the process-wide
+/// default (see [`StageCache::global`]), so a second call — whether a
+/// literal repeat or an edit elsewhere in a large graph — can skip a stage
+/// entirely once its inputs are byte-identical to a run already in the
+/// cache, rather than recomputing every pass from scratch. Bounded by
+/// [`STAGE_CACHE_CAPACITY`] with least-recently-used eviction, so it can't
+/// grow without bound across the lifetime of a long-running process.
+///
+/// Every pass in this crate currently reads the whole `LogicalGraph`
+/// (`sat_solver::detect_contradictions`, `graph::centrality::pagerank`, and
+/// the rest all take `&LogicalGraph`, not some smaller slice), so the
+/// "subgraph each stage depends on" is, for now, the whole graph for every
+/// stage — an edit anywhere invalidates all of them together. Narrowing
+/// that to each stage's true dependency would mean reworking those
+/// functions' signatures one at a time; this cache is keyed so that work
+/// can happen incrementally later without touching callers again.
+struct StageCache {
+    entries: Mutex<StageCacheEntries>,
+}
+
+#[derive(Default)]
+struct StageCacheEntries {
+    values: HashMap<(&'static str, u64), String>,
+    /// Recency order, oldest first; `get` and `insert` both move their key
+    /// to the back.
+    order: VecDeque<(&'static str, u64)>,
+}
+
+impl StageCache {
+    fn new() -> Self {
+        Self { entries: Mutex::new(StageCacheEntries::default()) }
+    }
+
+    /// The process-wide cache `AnalysisContext` uses unless constructed with
+    /// its own via `with_isolated_cache` — shared so that separate
+    /// `analyze`/`analyze_incremental` calls over the same graph content can
+    /// still reuse each other's stage results.
+    fn global() -> Arc<StageCache> {
+        static GLOBAL: OnceLock<Arc<StageCache>> = OnceLock::new();
+        GLOBAL.get_or_init(|| Arc::new(StageCache::new())).clone()
+    }
+
+    fn get(&self, key: &(&'static str, u64)) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap();
+        let value = entries.values.get(key).cloned()?;
+        if let Some(pos) = entries.order.iter().position(|k| k == key) {
+            entries.order.remove(pos);
+        }
+        entries.order.push_back(*key);
+        Some(value)
+    }
+
+    fn insert(&self, key: (&'static str, u64), value: String) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.values.insert(key, value).is_some() {
+            if let Some(pos) = entries.order.iter().position(|k| *k == key) {
+                entries.order.remove(pos);
+            }
+        }
+        entries.order.push_back(key);
+        while entries.order.len() > STAGE_CACHE_CAPACITY {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.values.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Hash a value's JSON serialization — the "content hash of the serialized
+/// subgraph" a stage depends on.
+fn content_hash<T: serde::Serialize>(value: &T) -> u64 {
+    let json = serde_json::to_string(value).unwrap_or_default();
+    let mut hasher = DefaultHasher::new();
+    json.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Read-through-or-compute against `cache`. On a hit, `stage` is recorded in
+/// `reused` so a caller can see which passes were actually skipped.
+fn cached<T, F>(cache: &StageCache, stage: &'static str, dependency_hash: u64, reused: &mut Vec<&'static str>, compute: F) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    F: FnOnce() -> T,
+{
+    let key = (stage, dependency_hash);
+    if let Some(json) = cache.get(&key) {
+        if let Ok(value) = serde_json::from_str(&json) {
+            reused.push(stage);
+            return value;
+        }
+    }
+    let value = compute();
+    if let Ok(json) = serde_json::to_string(&value) {
+        cache.insert(key, json);
+    }
+    value
+}
+
+/// A staged, memoizing wrapper around one graph's analysis: each pass is a
+/// focused method with its own cached field and a clear dependency on the
+/// passes before it (centrality feeds scoring and biases; cycles feed
+/// fallacies), computed lazily and cached both here (so calling a getter
+/// twice in the same run is free) and in its [`StageCache`] (so a later run
+/// over the same graph content is too). Consuming `self` via
+/// [`Self::into_result`] drops every interim field at once — nothing needs
+/// to manually clear the passes downstream stages no longer need.
+pub struct AnalysisContext {
+    graph: LogicalGraph,
+    graph_hash: u64,
+    search_budget: SearchBudget,
+    cache: Arc<StageCache>,
+    reused_stages: Vec<&'static str>,
+
+    contradictions: Option<Vec<Contradiction>>,
+    cycles: Option<Vec<Vec<String>>>,
+    cycles_overflowed: Option<bool>,
+    circular_reasoning: Option<Vec<ReasoningCycle>>,
+    topo_order: Option<Vec<String>>,
+    topo_order_condensed: Option<Vec<Vec<String>>>,
+    centrality: Option<HashMap<String, f64>>,
+    centrality_approximate: Option<bool>,
+    influence: Option<HashMap<String, f64>>,
+    evidence_strength: Option<HashMap<String, EvidenceStrength>>,
+    equivocations: Option<Vec<Equivocation>>,
+    scores: Option<Vec<ArgumentScore>>,
+    fallacies: Option<Vec<Fallacy>>,
+    biases: Option<Vec<CognitiveBias>>,
+    argument_camps: Option<Vec<Community>>,
+    retraction_suggestions: Option<Vec<RetractionSuggestion>>,
+    minimal_retraction_core: Option<Vec<String>>,
+    truth_labels: Option<Vec<PropositionStatus>>,
+    grounding_gaps: Option<Vec<GroundingGap>>,
+    aba_extensions: Option<AbaExtensions>,
+    questions: Option<Vec<Question>>,
+}
+
+impl AnalysisContext {
+    pub fn new(graph: LogicalGraph) -> Self {
+        Self::with_budget(graph, SearchBudget::default())
+    }
+
+    /// Like `new`, but with an explicit `SearchBudget` instead of
+    /// `SearchBudget::default()` — the centrality and cycle-detection stages
+    /// apply it via `betweenness_centrality_bounded` and
+    /// `enumerate_elementary_cycles_bounded`.
+    pub fn with_budget(graph: LogicalGraph, search_budget: SearchBudget) -> Self {
+        Self::with_budget_and_cache(graph, search_budget, StageCache::global())
+    }
+
+    /// Like `with_budget`, but backed by an explicit stage cache instead of
+    /// the process-wide default — so a run doesn't share cached results with
+    /// (or get served stale-looking stats from) any other analysis in the
+    /// process. Used by this module's own tests, each with its own private
+    /// `StageCache`, to stay independent of each other and of any other
+    /// caller's `analyze` calls while still exercising cross-call reuse.
+    #[cfg(test)]
+    fn with_isolated_cache(graph: LogicalGraph, search_budget: SearchBudget, cache: Arc<StageCache>) -> Self {
+        Self::with_budget_and_cache(graph, search_budget, cache)
+    }
+
+    fn with_budget_and_cache(graph: LogicalGraph, search_budget: SearchBudget, cache: Arc<StageCache>) -> Self {
+        let graph_hash = content_hash(&graph);
+        Self {
+            graph,
+            graph_hash,
+            search_budget,
+            cache,
+            reused_stages: Vec::new(),
+            contradictions: None,
+            cycles: None,
+            cycles_overflowed: None,
+            circular_reasoning: None,
+            topo_order: None,
+            topo_order_condensed: None,
+            centrality: None,
+            centrality_approximate: None,
+            influence: None,
+            evidence_strength: None,
+            equivocations: None,
+            scores: None,
+            fallacies: None,
+            biases: None,
+            argument_camps: None,
+            retraction_suggestions: None,
+            minimal_retraction_core: None,
+            truth_labels: None,
+            grounding_gaps: None,
+            aba_extensions: None,
+            questions: None,
+        }
+    }
+
+    /// Every stage name served from the global cache during this run.
+    pub fn reused_stages(&self) -> &[&'static str] {
+        &self.reused_stages
+    }
+
+    pub fn contradictions(&mut self) -> &[Contradiction] {
+        if self.contradictions.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.contradictions = Some(cached(cache, "contradictions", hash, reused, || crate::sat_solver::detect_contradictions(graph)));
+        }
+        self.contradictions.as_ref().expect("just populated")
+    }
+
+    pub fn cycles(&mut self) -> &[Vec<String>] {
+        self.ensure_cycles();
+        self.cycles.as_ref().expect("just populated")
+    }
+
+    /// Whether `cycles()` was cut short by `SearchBudget::max_cycles` or
+    /// `SearchBudget::max_cycle_search_visits` — more elementary cycles may
+    /// exist than were returned.
+    pub fn cycles_overflowed(&mut self) -> bool {
+        self.ensure_cycles();
+        self.cycles_overflowed.expect("just populated")
+    }
+
+    fn ensure_cycles(&mut self) {
+        if self.cycles.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            let budget = self.search_budget;
+            let (cycles, overflowed) = cached(cache, "cycles", hash, reused, || {
+                crate::graph::cycle_detection::enumerate_elementary_cycles_bounded(
+                    graph,
+                    budget.max_cycles,
+                    budget.max_cycle_search_visits,
+                )
+            });
+            self.cycles = Some(cycles);
+            self.cycles_overflowed = Some(overflowed);
+        }
+    }
+
+    fn circular_reasoning(&mut self) -> &[ReasoningCycle] {
+        if self.circular_reasoning.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.circular_reasoning = Some(cached(cache, "circular_reasoning", hash, reused, || {
+                crate::graph::cycle_detection::find_circular_reasoning(graph)
+            }));
+        }
+        self.circular_reasoning.as_ref().expect("just populated")
+    }
+
+    pub fn topo_order(&mut self) -> &[String] {
+        if self.topo_order.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.topo_order = Some(cached(cache, "topo_order", hash, reused, || crate::graph::topo_sort::topological_sort(graph)));
+        }
+        self.topo_order.as_ref().expect("just populated")
+    }
+
+    /// Like `topo_order`, but with cyclic sub-arguments collapsed into a
+    /// single cluster entry instead of dropped — see
+    /// `graph::topo_sort::topological_sort_condensed`.
+    pub fn topo_order_condensed(&mut self) -> &[Vec<String>] {
+        if self.topo_order_condensed.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.topo_order_condensed = Some(cached(cache, "topo_order_condensed", hash, reused, || {
+                crate::graph::topo_sort::topological_sort_condensed(graph)
+            }));
+        }
+        self.topo_order_condensed.as_ref().expect("just populated")
+    }
+
+    pub fn centrality(&mut self) -> &HashMap<String, f64> {
+        self.ensure_centrality();
+        self.centrality.as_ref().expect("just populated")
+    }
+
+    /// Whether `centrality()` is an estimate from `betweenness_centrality_bounded`'s
+    /// sampling mode rather than an exact score — see `SearchBudget::centrality_sampling_threshold`.
+    pub fn centrality_approximate(&mut self) -> bool {
+        self.ensure_centrality();
+        self.centrality_approximate.expect("just populated")
+    }
+
+    fn ensure_centrality(&mut self) {
+        if self.centrality.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            let budget = self.search_budget;
+            let (centrality, approximate) = cached(cache, "centrality", hash, reused, || {
+                crate::graph::centrality::betweenness_centrality_bounded(
+                    graph,
+                    budget.centrality_sampling_threshold,
+                    budget.centrality_sample_size,
+                )
+            });
+            self.centrality = Some(centrality);
+            self.centrality_approximate = Some(approximate);
+        }
+    }
+
+    fn influence(&mut self) -> &HashMap<String, f64> {
+        if self.influence.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.influence = Some(cached(cache, "influence", hash, reused, || crate::graph::centrality::pagerank(graph, 0.85, 100)));
+        }
+        self.influence.as_ref().expect("just populated")
+    }
+
+    fn evidence_strength(&mut self) -> &HashMap<String, EvidenceStrength> {
+        if self.evidence_strength.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.evidence_strength = Some(cached(cache, "evidence_strength", hash, reused, || {
+                crate::argument_scorer::propagate_evidence_strength(graph)
+            }));
+        }
+        self.evidence_strength.as_ref().expect("just populated")
+    }
+
+    fn equivocations(&mut self) -> Vec<Equivocation> {
+        if self.equivocations.is_none() {
+            let hash = self.graph_hash;
+            let contradictions = self.contradictions().to_vec();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.equivocations = Some(cached(cache, "equivocations", hash, reused, || {
+                crate::equivocation_detector::detect_equivocations(graph, &contradictions)
+            }));
+        }
+        self.equivocations.clone().expect("just populated")
+    }
+
+    pub fn scores(&mut self) -> Vec<ArgumentScore> {
+        if self.scores.is_none() {
+            let hash = self.graph_hash;
+            let contradictions = self.contradictions().to_vec();
+            let circular_reasoning = self.circular_reasoning().to_vec();
+            let centrality = self.centrality().clone();
+            let influence = self.influence().clone();
+            let evidence_strength = self.evidence_strength().clone();
+            let equivocations = self.equivocations();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.scores = Some(cached(cache, "scores", hash, reused, || {
+                crate::argument_scorer::score_arguments(
+                    graph,
+                    &contradictions,
+                    &centrality,
+                    &circular_reasoning,
+                    &influence,
+                    &evidence_strength,
+                    &equivocations,
+                )
+            }));
+        }
+        self.scores.clone().expect("just populated")
+    }
+
+    pub fn fallacies(&mut self) -> Vec<Fallacy> {
+        if self.fallacies.is_none() {
+            let hash = self.graph_hash;
+            let cycles = self.cycles().to_vec();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.fallacies = Some(cached(cache, "fallacies", hash, reused, || crate::fallacy_detector::detect_fallacies(graph, &cycles)));
+        }
+        self.fallacies.clone().expect("just populated")
+    }
+
+    pub fn biases(&mut self) -> Vec<CognitiveBias> {
+        if self.biases.is_none() {
+            let hash = self.graph_hash;
+            let centrality = self.centrality().clone();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.biases = Some(cached(cache, "biases", hash, reused, || crate::bias_detector::detect_biases(graph, &centrality)));
+        }
+        self.biases.clone().expect("just populated")
+    }
+
+    pub fn argument_camps(&mut self) -> Vec<Community> {
+        if self.argument_camps.is_none() {
+            let hash = self.graph_hash;
+            let contradictions = self.contradictions().to_vec();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.argument_camps = Some(cached(cache, "argument_camps", hash, reused, || {
+                crate::graph::community_detection::detect_argument_camps(graph, &contradictions)
+            }));
+        }
+        self.argument_camps.clone().expect("just populated")
+    }
+
+    pub fn retraction_suggestions(&mut self) -> Vec<RetractionSuggestion> {
+        if self.retraction_suggestions.is_none() {
+            let hash = self.graph_hash;
+            let contradictions = self.contradictions().to_vec();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.retraction_suggestions = Some(cached(cache, "retraction_suggestions", hash, reused, || {
+                crate::counterfactual::suggest_retractions(graph, &contradictions)
+            }));
+        }
+        self.retraction_suggestions.clone().expect("just populated")
+    }
+
+    /// The single smallest set of propositions whose retraction clears every
+    /// contradiction at once — see `sat_solver::minimal_contradiction_core`.
+    pub fn minimal_retraction_core(&mut self) -> Vec<String> {
+        if self.minimal_retraction_core.is_none() {
+            let hash = self.graph_hash;
+            let contradictions = self.contradictions().to_vec();
+            let (graph, reused, cache) = (&self.graph, &mut self.reused_stages, &self.cache);
+            self.minimal_retraction_core = Some(cached(cache, "minimal_retraction_core", hash, reused, || {
+                crate::sat_solver::minimal_contradiction_core(graph, &contradictions)
+            }));
+        }
+        self.minimal_retraction_core.clone().expect("just populated")
+    }
+
+    pub fn truth_labels(&mut self) -> &[PropositionStatus] {
+        if self.truth_labels.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.truth_labels = Some(cached(cache, "truth_labels", hash, reused, || crate::truth_propagation::propagate_truth(graph, true)));
+        }
+        self.truth_labels.as_ref().expect("just populated")
+    }
+
+    pub fn grounding_gaps(&mut self) -> &[GroundingGap] {
+        if self.grounding_gaps.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.grounding_gaps = Some(cached(cache, "grounding_gaps", hash, reused, || crate::grounding::validate_grounding(graph)));
+        }
+        self.grounding_gaps.as_ref().expect("just populated")
+    }
+
+    pub fn aba_extensions(&mut self) -> &AbaExtensions {
+        if self.aba_extensions.is_none() {
+            let (graph, hash, reused, cache) = (&self.graph, self.graph_hash, &mut self.reused_stages, &self.cache);
+            self.aba_extensions = Some(cached(cache, "aba_extensions", hash, reused, || crate::aba::compute_extensions(graph)));
+        }
+        self.aba_extensions.as_ref().expect("just populated")
+    }
+
+    /// The per-proposition entailment questions — not stage-cached like the
+    /// rest, since `entailment_evaluator::evaluate` runs once per
+    /// proposition rather than once over the whole graph, and its results
+    /// are deduplicated across propositions as they're collected.
+    pub fn questions(&mut self) -> &[Question] {
+        if self.questions.is_none() {
+            let mut questions = Vec::new();
+            let mut seen: HashSet<(String, String)> = HashSet::new();
+            for prop in &self.graph.propositions {
+                let (_, prop_questions) = crate::entailment_evaluator::evaluate(&self.graph, &prop.id);
+                for question in prop_questions {
+                    if seen.insert((question.target_id.clone(), question.unbound_atom.clone())) {
+                        questions.push(question);
+                    }
+                }
+            }
+            self.questions = Some(questions);
+        }
+        self.questions.as_ref().expect("just populated")
+    }
+
+    /// Force every stage, then assemble the final result. Consumes `self`
+    /// so all cached intermediates are dropped once this returns.
+    pub fn into_result(mut self) -> AnalysisResult {
+        let contradictions = self.contradictions().to_vec();
+        let cycles = self.cycles().to_vec();
+        let cycles_overflowed = self.cycles_overflowed();
+        let topological_order = self.topo_order().to_vec();
+        let topological_order_condensed = self.topo_order_condensed().to_vec();
+        let centrality_approximate = self.centrality_approximate();
+        let argument_scores = self.scores();
+        let equivocations = self.equivocations();
+        let fallacies = self.fallacies();
+        let biases = self.biases();
+        let argument_camps = self.argument_camps();
+        let retraction_suggestions = self.retraction_suggestions();
+        let minimal_retraction_core = self.minimal_retraction_core();
+        let truth_labels = self.truth_labels().to_vec();
+        let grounding_gaps = self.grounding_gaps().to_vec();
+        let aba_extensions = self.aba_extensions().clone();
+        let questions = self.questions().to_vec();
+        let search_budget = self.search_budget;
+
+        AnalysisResult {
+            contradictions,
+            fallacies,
+            biases,
+            argument_scores,
+            cycles,
+            topological_order,
+            topological_order_condensed,
+            argument_camps,
+            equivocations,
+            questions,
+            retraction_suggestions,
+            minimal_retraction_core,
+            truth_labels,
+            grounding_gaps,
+            aba_extensions,
+            search_budget,
+            overflow: AnalysisOverflow { centrality_approximate, cycles_overflowed },
+        }
+    }
+}
+
+/// Run the full pipeline over `graph`, recording which stages were served
+/// from the process-wide content-hash cache (see [`StageCache::global`]).
+pub fn analyze(graph: LogicalGraph) -> (AnalysisResult, Vec<&'static str>) {
+    run_all_stages(AnalysisContext::new(graph))
+}
+
+/// Touch every stage on `context`, then assemble the result — shared by
+/// `analyze` and this module's tests (the latter via a context backed by an
+/// isolated cache instead of the process-wide default).
+fn run_all_stages(mut context: AnalysisContext) -> (AnalysisResult, Vec<&'static str>) {
+    // Touch every stage so `reused_stages` reflects the whole pipeline
+    // (`into_result` would otherwise lazily skip stages nothing else
+    // reaches, like `aba_extensions`, until it gets to them itself — which
+    // it does, but recording it explicitly here keeps this function's
+    // contract — "every stage ran or was reused" — independent of
+    // `into_result`'s internal ordering).
+    context.contradictions();
+    context.cycles();
+    context.topo_order();
+    context.topo_order_condensed();
+    context.centrality();
+    context.scores();
+    context.fallacies();
+    context.biases();
+    context.argument_camps();
+    context.retraction_suggestions();
+    context.minimal_retraction_core();
+    context.truth_labels();
+    context.grounding_gaps();
+    context.aba_extensions();
+    context.questions();
+    let reused = context.reused_stages().to_vec();
+    (context.into_result(), reused)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    /// Like `analyze`, but backed by `cache` rather than the process-wide
+    /// default, so concurrently-run tests never share cache state with each
+    /// other — only, intentionally, across their own repeated calls.
+    fn analyze_isolated(graph: LogicalGraph, cache: &Arc<StageCache>) -> (AnalysisResult, Vec<&'static str>) {
+        run_all_stages(AnalysisContext::with_isolated_cache(graph, SearchBudget::default(), cache.clone()))
+    }
+
+    #[test]
+    fn test_repeat_analysis_of_identical_graph_reuses_every_stage() {
+        let graph = make_graph(
+            vec![make_prop("E_repeat", "evidence", "high"), make_prop("C_repeat", "claim", "high")],
+            vec![make_rel("r_repeat", "E_repeat", "C_repeat", "supports")],
+        );
+        let cache = Arc::new(StageCache::new());
+        let (_, first_reused) = analyze_isolated(graph.clone(), &cache);
+        let (_, second_reused) = analyze_isolated(graph, &cache);
+        assert!(first_reused.is_empty());
+        assert!(second_reused.contains(&"contradictions"));
+        assert!(second_reused.contains(&"centrality"));
+        assert!(second_reused.contains(&"aba_extensions"));
+    }
+
+    #[test]
+    fn test_edited_graph_does_not_reuse_stale_stages() {
+        let graph = make_graph(vec![make_prop("C_edit", "claim", "high")], vec![]);
+        let mut edited = graph.clone();
+        edited.propositions.push(make_prop("E_edit", "evidence", "high"));
+
+        let cache = Arc::new(StageCache::new());
+        let (_, _) = analyze_isolated(graph, &cache);
+        let (_, reused_after_edit) = analyze_isolated(edited, &cache);
+        assert!(!reused_after_edit.contains(&"contradictions"));
+    }
+
+    #[test]
+    fn test_into_result_matches_analyze_native_shape() {
+        let graph = make_graph(
+            vec![make_prop("E_shape", "evidence", "high"), make_prop("C_shape", "claim", "high")],
+            vec![make_rel("r_shape", "E_shape", "C_shape", "supports")],
+        );
+        let (result, _) = analyze_isolated(graph, &Arc::new(StageCache::new()));
+        assert!(result.contradictions.is_empty());
+        assert_eq!(result.topological_order.len(), 2);
+    }
+
+    #[test]
+    fn test_stage_cache_evicts_least_recently_used_entry_past_capacity() {
+        let cache = StageCache::new();
+        for i in 0..=STAGE_CACHE_CAPACITY {
+            cache.insert(("stage", i as u64), format!("value-{i}"));
+        }
+        assert!(cache.get(&("stage", 0)).is_none(), "oldest entry should have been evicted");
+        assert!(cache.get(&("stage", STAGE_CACHE_CAPACITY as u64)).is_some());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::types::{LogicalGraph, Proposition, ReconstructionInput, ReconstructionResult, Relationship};
+
+/// Build a `LogicalGraph` from an explicit reason/conclusion reconstruction:
+/// each reason becomes an `evidence` proposition, each conclusion a
+/// load-bearing `claim` proposition, and every reason gets a `supports`
+/// edge to every conclusion given alongside it — the usual shape for a
+/// single argument with several premises jointly backing one or more
+/// conclusions. The resulting graph flows unchanged into
+/// `analyze_native`'s contradiction/fallacy/bias passes, the same as a
+/// hand-authored one.
+pub fn build_from_reasons(input: &ReconstructionInput) -> ReconstructionResult {
+    let mut warnings = Vec::new();
+    if input.reasons.is_empty() {
+        warnings.push("no reason statements given — conclusions will be unsupported claims".to_string());
+    }
+    if input.conclusions.is_empty() {
+        warnings.push("no conclusion statements given — reasons have nothing to support".to_string());
+    }
+
+    let mut propositions = Vec::new();
+    let source = if input.source.trim().is_empty() { None } else { Some(input.source.clone()) };
+
+    let reason_ids: Vec<String> = input
+        .reasons
+        .iter()
+        .enumerate()
+        .map(|(i, statement)| {
+            let id = format!("reason_{}", i + 1);
+            propositions.push(synthesized_proposition(&id, statement, "evidence", false, source.clone()));
+            id
+        })
+        .collect();
+
+    let conclusion_ids: Vec<String> = input
+        .conclusions
+        .iter()
+        .enumerate()
+        .map(|(i, statement)| {
+            let id = format!("conclusion_{}", i + 1);
+            propositions.push(synthesized_proposition(&id, statement, "claim", true, source.clone()));
+            id
+        })
+        .collect();
+
+    let mut relationships = Vec::new();
+    let mut rel_counter = 0u32;
+    for reason_id in &reason_ids {
+        for conclusion_id in &conclusion_ids {
+            rel_counter += 1;
+            relationships.push(Relationship {
+                id: format!("rel_{}", rel_counter),
+                from_id: reason_id.clone(),
+                to_id: conclusion_id.clone(),
+                rel_type: "supports".to_string(),
+                strength: "moderate".to_string(),
+                label: None,
+            });
+        }
+    }
+
+    ReconstructionResult { graph: LogicalGraph { propositions, relationships }, warnings }
+}
+
+/// Parse a lightweight argdown-style indented list into a `LogicalGraph`:
+/// each line is a statement, optionally titled `<Name>: text` (becomes a
+/// `claim`; an untitled line becomes `evidence`), and a line indented
+/// deeper than its predecessor and marked `+`/`-` becomes a child that
+/// `supports`/`attacks` the nearest shallower-indented line above it.
+/// Blank lines are ignored; a `+`/`-` line with no shallower ancestor to
+/// attach to is recorded as a warning rather than silently dropped, and so
+/// is a titled line with no statement text after its `:`.
+pub fn parse_argdown(text: &str) -> ReconstructionResult {
+    let mut propositions = Vec::new();
+    let mut relationships = Vec::new();
+    let mut warnings = Vec::new();
+    // (indent width, node id) of every line still "in scope" as a possible
+    // parent, shallowest first.
+    let mut ancestors: Vec<(usize, String)> = Vec::new();
+    let mut node_counter = 0u32;
+    let mut rel_counter = 0u32;
+
+    for (line_no, raw_line) in text.lines().enumerate() {
+        if raw_line.trim().is_empty() {
+            continue;
+        }
+        let indent = raw_line.len() - raw_line.trim_start().len();
+        let trimmed = raw_line.trim();
+
+        let (marker, rest) = match trimmed.chars().next() {
+            Some(c @ ('+' | '-')) => (Some(c), trimmed[1..].trim_start()),
+            _ => (None, trimmed),
+        };
+
+        let (title, statement) = match parse_title(rest) {
+            Some((title, statement)) => (Some(title), statement),
+            None => (None, rest.to_string()),
+        };
+
+        if statement.is_empty() {
+            warnings.push(format!("line {}: no statement text found in {:?}", line_no + 1, raw_line));
+            continue;
+        }
+
+        node_counter += 1;
+        let is_titled = title.is_some();
+        let id = title.unwrap_or_else(|| format!("node_{}", node_counter));
+        let prop_type = if is_titled { "claim" } else { "evidence" };
+        propositions.push(synthesized_proposition(&id, &statement, prop_type, is_titled, None));
+
+        while ancestors.last().is_some_and(|(top_indent, _)| *top_indent >= indent) {
+            ancestors.pop();
+        }
+
+        if let Some(marker) = marker {
+            if let Some((_, parent_id)) = ancestors.last() {
+                rel_counter += 1;
+                relationships.push(Relationship {
+                    id: format!("rel_{}", rel_counter),
+                    from_id: id.clone(),
+                    to_id: parent_id.clone(),
+                    rel_type: if marker == '+' { "supports" } else { "attacks" }.to_string(),
+                    strength: "moderate".to_string(),
+                    label: None,
+                });
+            } else {
+                warnings.push(format!(
+                    "line {}: '{}' marker has no less-indented statement above it to attach to",
+                    line_no + 1,
+                    marker
+                ));
+            }
+        }
+
+        ancestors.push((indent, id));
+    }
+
+    ReconstructionResult { graph: LogicalGraph { propositions, relationships }, warnings }
+}
+
+/// Split a `<Title>: statement` line into its title and statement; `None`
+/// if the line doesn't open with `<`.
+fn parse_title(rest: &str) -> Option<(String, String)> {
+    if !rest.starts_with('<') {
+        return None;
+    }
+    let end = rest.find('>')?;
+    let title = rest[1..end].trim().to_string();
+    let remainder = rest[end + 1..].trim_start().strip_prefix(':').unwrap_or(&rest[end + 1..]).trim();
+    Some((title, remainder.to_string()))
+}
+
+fn synthesized_proposition(
+    id: &str,
+    statement: &str,
+    prop_type: &str,
+    is_load_bearing: bool,
+    source: Option<String>,
+) -> Proposition {
+    Proposition {
+        id: id.to_string(),
+        statement: statement.to_string(),
+        formal_expression: format!("{} → true", id),
+        prop_type: prop_type.to_string(),
+        confidence: "medium".to_string(),
+        is_implicit: false,
+        is_load_bearing,
+        is_anchored: false,
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_from_reasons_wires_every_reason_to_every_conclusion() {
+        let input = ReconstructionInput {
+            source: "analyst".to_string(),
+            reasons: vec!["Sales grew 20%".to_string(), "Costs fell 5%".to_string()],
+            conclusions: vec!["The business is healthy".to_string()],
+        };
+        let result = build_from_reasons(&input);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.graph.propositions.len(), 3);
+        assert_eq!(result.graph.relationships.len(), 2);
+        assert!(result.graph.relationships.iter().all(|r| r.rel_type == "supports" && r.to_id == "conclusion_1"));
+        assert_eq!(result.graph.get_proposition("reason_1").unwrap().source, Some("analyst".to_string()));
+    }
+
+    #[test]
+    fn test_build_from_reasons_warns_on_missing_fields() {
+        let input = ReconstructionInput { source: String::new(), reasons: vec![], conclusions: vec![] };
+        let result = build_from_reasons(&input);
+        assert_eq!(result.warnings.len(), 2);
+        assert!(result.graph.propositions.is_empty());
+    }
+
+    #[test]
+    fn test_parse_argdown_builds_titled_claim_with_supporting_reason() {
+        let text = "<Conclusion>: The plan will work\n  + Evidence from last quarter's numbers";
+        let result = parse_argdown(text);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.graph.propositions.len(), 2);
+        let conclusion = result.graph.get_proposition("Conclusion").unwrap();
+        assert_eq!(conclusion.prop_type, "claim");
+        assert_eq!(conclusion.statement, "The plan will work");
+        assert_eq!(result.graph.relationships.len(), 1);
+        assert_eq!(result.graph.relationships[0].rel_type, "supports");
+        assert_eq!(result.graph.relationships[0].to_id, "Conclusion");
+    }
+
+    #[test]
+    fn test_parse_argdown_attacks_marker_produces_attacks_edge() {
+        let text = "<Conclusion>: The plan will work\n  - The budget was already overspent";
+        let result = parse_argdown(text);
+        assert_eq!(result.graph.relationships[0].rel_type, "attacks");
+    }
+
+    #[test]
+    fn test_parse_argdown_orphan_marker_is_a_warning_not_a_drop() {
+        let text = "  + An orphaned reason with no parent above it";
+        let result = parse_argdown(text);
+        assert_eq!(result.graph.propositions.len(), 1);
+        assert!(result.graph.relationships.is_empty());
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_argdown_empty_titled_line_is_a_warning() {
+        let text = "<Empty>:";
+        let result = parse_argdown(text);
+        assert!(result.graph.propositions.is_empty());
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_argdown_nested_reasons_attach_to_nearest_shallower_ancestor() {
+        let text = "<C>: top claim\n  + first-level reason\n    + second-level sub-reason";
+        let result = parse_argdown(text);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.graph.relationships.len(), 2);
+        let to_c = result.graph.relationships.iter().find(|r| r.to_id == "C").unwrap();
+        assert_eq!(to_c.from_id, "node_2");
+        let to_reason = result.graph.relationships.iter().find(|r| r.to_id != "C").unwrap();
+        assert_eq!(to_reason.from_id, "node_3");
+        assert_eq!(to_reason.to_id, "node_2");
+    }
+}
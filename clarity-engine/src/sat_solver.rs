@@ -1,11 +1,17 @@
 use crate::types::{Contradiction, LogicalGraph};
+use std::collections::{HashMap, HashSet};
 
 /// Detect contradictions in the proposition set.
 ///
 /// Strategy (pragmatic for hackathon, but real computation):
 /// 1. EXPLICIT contradictions: relationships of type "contradicts"
-/// 2. TEMPORAL contradictions: incompatible time constraints
-/// 3. LOGICAL contradictions: "X → Y" vs "X → ¬Y" patterns in formal expressions
+/// 2. TEMPORAL contradictions: Allen-relation assertions (`before(a, b)`,
+///    `overlaps(a, b)`, ...) whose induced point-algebra constraint network
+///    is inconsistent under path consistency (see `temporal_algebra`)
+/// 3. LOGICAL contradictions: compile every `formal_expression` into CNF and
+///    run DPLL jointly over the whole proposition set (see
+///    `detect_boolean_contradiction`) — a genuine unsatisfiability check
+///    rather than pairwise string matching
 /// 4. RESOURCE contradictions: competing constraints on same variable
 pub fn detect_contradictions(graph: &LogicalGraph) -> Vec<Contradiction> {
     let mut contradictions = Vec::new();
@@ -37,344 +43,1190 @@ pub fn detect_contradictions(graph: &LogicalGraph) -> Vec<Contradiction> {
                         "\"{}\" directly contradicts \"{}\". These two propositions cannot both be true simultaneously.",
                         from_prop.statement, to_prop.statement
                     ),
+                    proof: Vec::new(),
                 });
             }
         }
     }
 
-    // ── Strategy 2: Temporal contradiction detection ──
-    // Look for propositions with time-related formal expressions that conflict
-    let time_keywords = [
-        "month", "year", "week", "day", "quarter", "time", "duration",
-        "deadline", "runway", "period",
-    ];
+    // ── Strategy 2: Temporal reasoning via Allen's interval algebra ──
+    if let Some((proof, proposition_ids)) = crate::temporal_algebra::detect_temporal_contradiction(graph) {
+        counter += 1;
+        let load_bearing = proposition_ids
+            .iter()
+            .filter_map(|id| graph.get_proposition(id))
+            .any(|p| p.is_load_bearing);
+        let statements: Vec<String> = proposition_ids
+            .iter()
+            .filter_map(|id| graph.get_proposition(id))
+            .map(|p| format!("\"{}\"", p.statement))
+            .collect();
 
-    let time_props: Vec<&crate::types::Proposition> = graph
-        .propositions
-        .iter()
-        .filter(|p| {
-            let expr_lower = p.formal_expression.to_lowercase();
-            let stmt_lower = p.statement.to_lowercase();
-            time_keywords.iter().any(|kw| expr_lower.contains(kw) || stmt_lower.contains(kw))
-        })
-        .collect();
+        contradictions.push(Contradiction {
+            id: format!("contradiction-temporal-{}", counter),
+            proposition_ids,
+            contradiction_type: "temporal".to_string(),
+            severity: if load_bearing { "critical" } else { "major" }.to_string(),
+            formal_proof: render_derivation(&proof),
+            human_explanation: format!(
+                "The timing relationships asserted in {} are jointly impossible: path consistency \
+                 over their interval constraints derives an empty relation between two points in time.",
+                statements.join(", ")
+            ),
+            proof,
+        });
+    }
 
-    // Check pairs of time-related propositions for conflicts
-    for i in 0..time_props.len() {
-        for j in (i + 1)..time_props.len() {
-            let a = time_props[i];
-            let b = time_props[j];
+    // ── Strategy 3: Joint boolean satisfiability via DPLL ──
+    if let Some(contradiction) = detect_boolean_contradiction(graph, &mut counter) {
+        contradictions.push(contradiction);
+    }
 
-            // Detect "takes > X" vs "needs < Y" where X > Y
-            if let Some(explanation) = detect_temporal_conflict(a, b) {
-                counter += 1;
-                let severity = if a.is_load_bearing || b.is_load_bearing {
-                    "critical"
-                } else {
-                    "major"
-                };
+    // ── Strategy 4: Resource / numeric contradictions ──
+    // Check if savings / expenses math doesn't add up
+    detect_resource_contradictions(graph, &mut contradictions, &mut counter);
 
-                contradictions.push(Contradiction {
-                    id: format!("contradiction-temporal-{}", counter),
-                    proposition_ids: vec![a.id.clone(), b.id.clone()],
-                    contradiction_type: "temporal".to_string(),
-                    severity: severity.to_string(),
-                    formal_proof: format!(
-                        "{} ∧ {} → temporal_conflict",
-                        a.formal_expression, b.formal_expression
-                    ),
-                    human_explanation: explanation,
-                });
+    contradictions
+}
+
+/// Parse an implication "X → Y" from a formal expression.
+pub(crate) fn parse_implication(expr: &str) -> Option<(String, String)> {
+    // Try "→" first, then "->"
+    let arrow_patterns = ["→", "->"];
+    for arrow in &arrow_patterns {
+        if let Some(pos) = expr.find(arrow) {
+            let lhs = expr[..pos].trim().to_string();
+            let rhs = expr[pos + arrow.len()..].trim().to_string();
+            if !lhs.is_empty() && !rhs.is_empty() {
+                return Some((lhs, rhs));
             }
         }
     }
+    None
+}
 
-    // ── Strategy 3: Logical implication conflicts ──
-    // Parse formal expressions for "X → Y" and "X → ¬Y" patterns
-    for i in 0..graph.propositions.len() {
-        for j in (i + 1)..graph.propositions.len() {
-            let a = &graph.propositions[i];
-            let b = &graph.propositions[j];
+/// A single CNF clause compiled from one proposition's `formal_expression`,
+/// tagged with the proposition it came from so a failing clause set can be
+/// traced back to the propositions responsible for it.
+pub(crate) struct Clause {
+    /// Literals in DIMACS-style encoding: a variable's 1-indexed id, negative
+    /// when the literal is negated.
+    pub(crate) literals: Vec<i32>,
+    pub(crate) source_prop: String,
+}
 
-            if let Some(explanation) = detect_logical_conflict(a, b) {
-                counter += 1;
-                let severity = if a.is_load_bearing && b.is_load_bearing {
-                    "critical"
-                } else {
-                    "major"
-                };
-                contradictions.push(Contradiction {
-                    id: format!("contradiction-logical-{}", counter),
-                    proposition_ids: vec![a.id.clone(), b.id.clone()],
-                    contradiction_type: "logical".to_string(),
-                    severity: severity.to_string(),
-                    formal_proof: format!(
-                        "{} ∧ {} → ⊥",
-                        a.formal_expression, b.formal_expression
-                    ),
-                    human_explanation: explanation,
+/// Compile every proposition's `formal_expression` into CNF clauses jointly:
+/// `X → Y` becomes `(¬X ∨ Y)`, a bare literal `X` (or `¬X`) becomes a unit
+/// clause, and `∧`-joined antecedents/consequents are split into their own
+/// clauses. Atomic predicates are treated as opaque boolean variables keyed
+/// by their trimmed text, so "growth" in one proposition and "growth" in
+/// another refer to the same variable.
+pub(crate) fn compile_to_cnf(propositions: &[crate::types::Proposition]) -> (Vec<Clause>, HashMap<String, usize>) {
+    let mut var_index: HashMap<String, usize> = HashMap::new();
+    let mut clauses = Vec::new();
+
+    for prop in propositions {
+        let expr = prop.formal_expression.trim();
+        if expr.is_empty() {
+            continue;
+        }
+
+        if let Some((lhs, rhs)) = parse_implication(expr) {
+            let antecedent: Vec<i32> = split_conjuncts(&lhs)
+                .iter()
+                .map(|atom| -literal_for(atom, &mut var_index))
+                .collect();
+            for consequent in split_conjuncts(&rhs) {
+                let mut literals = antecedent.clone();
+                literals.push(literal_for(&consequent, &mut var_index));
+                clauses.push(Clause { literals, source_prop: prop.id.clone() });
+            }
+        } else {
+            for atom in split_conjuncts(expr) {
+                clauses.push(Clause {
+                    literals: vec![literal_for(&atom, &mut var_index)],
+                    source_prop: prop.id.clone(),
                 });
             }
         }
     }
 
-    // ── Strategy 4: Resource / numeric contradictions ──
-    // Check if savings / expenses math doesn't add up
-    detect_resource_contradictions(graph, &mut contradictions, &mut counter);
+    (clauses, var_index)
+}
 
-    contradictions
+/// Split a conjunction ("A ∧ B ∧ C") into its conjuncts; a non-conjunction
+/// expression yields a single-element vector.
+pub(crate) fn split_conjuncts(expr: &str) -> Vec<String> {
+    expr.split('∧')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
 }
 
-/// Detect temporal conflicts between two propositions.
-/// Returns Some(explanation) if a conflict is found.
-fn detect_temporal_conflict(
-    a: &crate::types::Proposition,
-    b: &crate::types::Proposition,
-) -> Option<String> {
-    let a_expr = a.formal_expression.to_lowercase();
-    let b_expr = b.formal_expression.to_lowercase();
-    let a_stmt = a.statement.to_lowercase();
-    let b_stmt = b.statement.to_lowercase();
-
-    // Look for duration indicators: "> N months/years" or "< N months/years"
-    let a_duration = extract_duration(&a_expr).or_else(|| extract_duration(&a_stmt));
-    let b_duration = extract_duration(&b_expr).or_else(|| extract_duration(&b_stmt));
-
-    if let (Some((a_op, a_val)), Some((b_op, b_val))) = (a_duration, b_duration) {
-        // "takes > 12 months" vs "needs results in < 6 months" → conflict
-        if a_op == ">" && b_op == "<" && a_val > b_val {
-            return Some(format!(
-                "Temporal conflict: \"{}\" implies a duration of more than {} months, \
-                 but \"{}\" requires completion within {} months. \
-                 These time constraints are incompatible.",
-                a.statement, a_val, b.statement, b_val
-            ));
-        }
-        if b_op == ">" && a_op == "<" && b_val > a_val {
-            return Some(format!(
-                "Temporal conflict: \"{}\" implies a duration of more than {} months, \
-                 but \"{}\" requires completion within {} months. \
-                 These time constraints are incompatible.",
-                b.statement, b_val, a.statement, a_val
-            ));
-        }
-    }
-
-    // Check for urgency vs long duration
-    let a_urgent = a_stmt.contains("now") || a_stmt.contains("immediately") || a_stmt.contains("should");
-    let b_urgent = b_stmt.contains("now") || b_stmt.contains("immediately") || b_stmt.contains("should");
-    let a_long = a_stmt.contains(">12") || a_stmt.contains("over a year") || a_stmt.contains("> 12");
-    let b_long = b_stmt.contains(">12") || b_stmt.contains("over a year") || b_stmt.contains("> 12");
-
-    if (a_urgent && b_long) || (b_urgent && a_long) {
-        return Some(format!(
-            "Temporal conflict: \"{}\" implies urgency, \
-             but \"{}\" indicates a lengthy timeline. \
-             The urgency and the required duration are incompatible.",
-            if a_urgent { &a.statement } else { &b.statement },
-            if a_long { &a.statement } else { &b.statement },
-        ));
+/// Strip a leading `¬`/`!` negation marker, returning the bare atom name.
+pub(crate) fn atom_name(raw: &str) -> String {
+    raw.trim().trim_start_matches('¬').trim_start_matches('!').trim().to_string()
+}
+
+/// Map an atom (optionally negated with `¬` or `!`) to a signed DIMACS
+/// literal, assigning it a fresh variable id the first time it's seen.
+fn literal_for(atom: &str, var_index: &mut HashMap<String, usize>) -> i32 {
+    let trimmed = atom.trim();
+    let negated = trimmed.starts_with('¬') || trimmed.starts_with('!');
+    let name = atom_name(trimmed);
+    let next_id = var_index.len() + 1;
+    let id = *var_index.entry(name).or_insert(next_id);
+    if negated {
+        -(id as i32)
+    } else {
+        id as i32
     }
+}
 
-    None
+/// DPLL satisfiability check: unit propagation to a fixpoint, then branch on
+/// an unassigned variable and recurse, backtracking on conflict.
+fn dpll_satisfiable(clauses: &[Vec<i32>], num_vars: usize) -> bool {
+    let assignment: Vec<Option<bool>> = vec![None; num_vars + 1];
+    dpll(clauses, assignment).is_some()
+}
+
+/// Like `dpll_satisfiable`, but returns the satisfying assignment itself
+/// rather than just whether one exists — the building block for `aba`'s
+/// model enumeration, which needs to read back which variables came out
+/// true.
+pub(crate) fn dpll_model(clauses: &[Vec<i32>], num_vars: usize) -> Option<Vec<Option<bool>>> {
+    let assignment: Vec<Option<bool>> = vec![None; num_vars + 1];
+    dpll(clauses, assignment)
 }
 
-/// Extract a duration from text like "> 12 months" or "< 6 months"
-/// Returns (operator, value_in_months)
-fn extract_duration(text: &str) -> Option<(String, f64)> {
-    // Pattern: "> N month" or "< N month" or "> N year"
-    let patterns: &[(&str, f64)] = &[
-        ("month", 1.0),
-        ("year", 12.0),
-        ("week", 0.25),
-    ];
-
-    for &(unit, multiplier) in patterns {
-        if let Some(pos) = text.find(unit) {
-            // Look backwards from the unit for a number and operator
-            let before = text[..pos].trim_end();
-            // Find the last number in the string before the unit
-            let mut parts: Vec<&str> = before.split_whitespace().collect();
-            parts.reverse();
-            for (i, part) in parts.iter().enumerate() {
-                let trimmed: String = part.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
-                if let Ok(val) = trimmed.parse::<f64>() {
-                    // Check for operator
-                    let op = if i + 1 < parts.len() {
-                        match parts[i + 1] {
-                            ">" | ">=" => ">",
-                            "<" | "<=" => "<",
-                            _ => {
-                                // Check if current part has operator prefix
-                                if part.starts_with('>') { ">" }
-                                else if part.starts_with('<') { "<" }
-                                else { "=" }
-                            }
-                        }
-                    } else if part.starts_with('>') { ">" }
-                      else if part.starts_with('<') { "<" }
-                      else { "=" };
-
-                    return Some((op.to_string(), val * multiplier));
+fn dpll(clauses: &[Vec<i32>], assignment: Vec<Option<bool>>) -> Option<Vec<Option<bool>>> {
+    let assignment = propagate_units(clauses, assignment)?;
+
+    // Find an unassigned variable to branch on; if every clause is already
+    // satisfied and none remains, the current assignment is a model.
+    let mut branch_var = None;
+    for clause in clauses {
+        match clause_status(clause, &assignment) {
+            ClauseStatus::Conflict => return None,
+            ClauseStatus::Unresolved => {
+                if branch_var.is_none() {
+                    branch_var = clause
+                        .iter()
+                        .find(|lit| assignment[lit.unsigned_abs() as usize].is_none())
+                        .map(|lit| lit.unsigned_abs() as usize);
                 }
             }
+            ClauseStatus::Satisfied | ClauseStatus::Unit(_) => {}
+        }
+    }
+
+    let Some(var) = branch_var else { return Some(assignment) };
+
+    for &value in &[true, false] {
+        let mut trial = assignment.clone();
+        trial[var] = Some(value);
+        if let Some(solution) = dpll(clauses, trial) {
+            return Some(solution);
         }
     }
     None
 }
 
-/// Detect logical conflicts: "X → Y" in one prop and "X → ¬Y" in another.
-fn detect_logical_conflict(
-    a: &crate::types::Proposition,
-    b: &crate::types::Proposition,
-) -> Option<String> {
-    let a_expr = &a.formal_expression;
-    let b_expr = &b.formal_expression;
+/// Unit-propagate `clauses` from `assignment` to a fixpoint without
+/// branching — the sound-but-incomplete core of DPLL, useful on its own
+/// when only logically *forced* values are wanted (see
+/// `entailment_evaluator`) rather than a full satisfying model. Returns
+/// `None` if propagation alone derives a conflict.
+pub(crate) fn propagate_units(
+    clauses: &[Vec<i32>],
+    mut assignment: Vec<Option<bool>>,
+) -> Option<Vec<Option<bool>>> {
+    loop {
+        let mut propagated = false;
+        for clause in clauses {
+            match clause_status(clause, &assignment) {
+                ClauseStatus::Conflict => return None,
+                ClauseStatus::Unit(lit) => {
+                    assignment[lit.unsigned_abs() as usize] = Some(lit > 0);
+                    propagated = true;
+                }
+                ClauseStatus::Satisfied | ClauseStatus::Unresolved => {}
+            }
+        }
+        if !propagated {
+            break;
+        }
+    }
+    Some(assignment)
+}
 
-    // Look for implication pattern: "X → Y" and "X → ¬Y"
-    if let (Some((a_lhs, a_rhs)), Some((b_lhs, b_rhs))) =
-        (parse_implication(a_expr), parse_implication(b_expr))
-    {
-        let a_lhs_t = a_lhs.trim();
-        let b_lhs_t = b_lhs.trim();
-        let a_rhs_t = a_rhs.trim();
-        let b_rhs_t = b_rhs.trim();
-
-        // Same antecedent, negated consequent
-        if a_lhs_t == b_lhs_t {
-            let a_negated = format!("¬{}", a_rhs_t);
-            let b_negated = format!("¬{}", b_rhs_t);
-            let a_stripped = a_rhs_t.trim_start_matches('¬').trim();
-            let b_stripped = b_rhs_t.trim_start_matches('¬').trim();
-
-            if a_rhs_t == b_negated || b_rhs_t == a_negated || a_stripped == b_stripped && a_rhs_t != b_rhs_t {
-                return Some(format!(
-                    "Logical conflict: \"{}\" implies {} → {}, \
-                     but \"{}\" implies {} → {}. \
-                     Given the same condition ({}), these lead to contradictory conclusions.",
-                    a.statement, a_lhs_t, a_rhs_t,
-                    b.statement, b_lhs_t, b_rhs_t,
-                    a_lhs_t
-                ));
+enum ClauseStatus {
+    Satisfied,
+    Conflict,
+    Unit(i32),
+    Unresolved,
+}
+
+/// Classify a clause under the current partial assignment.
+fn clause_status(clause: &[i32], assignment: &[Option<bool>]) -> ClauseStatus {
+    let mut unassigned: Option<i32> = None;
+    let mut unassigned_count = 0;
+    for &lit in clause {
+        match assignment[lit.unsigned_abs() as usize] {
+            Some(value) if (lit > 0) == value => return ClauseStatus::Satisfied,
+            Some(_) => {}
+            None => {
+                unassigned_count += 1;
+                unassigned = Some(lit);
             }
         }
     }
+    match unassigned_count {
+        0 => ClauseStatus::Conflict,
+        1 => ClauseStatus::Unit(unassigned.unwrap()),
+        _ => ClauseStatus::Unresolved,
+    }
+}
 
-    None
+/// Compile every proposition's `formal_expression` into CNF and run DPLL
+/// jointly over the whole set. If the set is satisfiable, there's no
+/// boolean-level contradiction; if it's UNSAT, [`minimal_unsat_core`]
+/// extracts the irreducible conflicting subset and the resolution proof
+/// that refutes it.
+fn detect_boolean_contradiction(graph: &LogicalGraph, counter: &mut u32) -> Option<Contradiction> {
+    let (clauses, var_index) = compile_to_cnf(&graph.propositions);
+    if clauses.is_empty() {
+        return None;
+    }
+    let num_vars = var_index.len();
+
+    let all_literals: Vec<Vec<i32>> = clauses.iter().map(|c| c.literals.clone()).collect();
+    if dpll_satisfiable(&all_literals, num_vars) {
+        return None;
+    }
+
+    let mut candidates: Vec<String> = clauses.iter().map(|c| c.source_prop.clone()).collect();
+    candidates.sort();
+    candidates.dedup();
+    candidates.sort_by(|a, b| {
+        let a_load_bearing = graph.get_proposition(a).map(|p| p.is_load_bearing).unwrap_or(false);
+        let b_load_bearing = graph.get_proposition(b).map(|p| p.is_load_bearing).unwrap_or(false);
+        a_load_bearing.cmp(&b_load_bearing).then_with(|| a.cmp(b))
+    });
+
+    let tree = minimal_unsat_core(&clauses, &var_index, &candidates);
+    let proposition_ids = tree.core_proposition_ids;
+    let proof = tree.steps;
+
+    *counter += 1;
+    let severity = if proposition_ids
+        .iter()
+        .filter_map(|id| graph.get_proposition(id))
+        .any(|p| p.is_load_bearing)
+    {
+        "critical"
+    } else {
+        "major"
+    };
+    let expressions: Vec<&str> = proposition_ids
+        .iter()
+        .filter_map(|id| graph.get_proposition(id))
+        .map(|p| p.formal_expression.as_str())
+        .collect();
+    let statements: Vec<&str> = proposition_ids
+        .iter()
+        .filter_map(|id| graph.get_proposition(id))
+        .map(|p| p.statement.as_str())
+        .collect();
+
+    let formal_proof = if proof.is_empty() {
+        format!("{} → ⊥ (minimal DPLL-unsat core)", expressions.join(" ∧ "))
+    } else {
+        render_derivation(&proof)
+    };
+
+    Some(Contradiction {
+        id: format!("contradiction-boolean-{}", counter),
+        proposition_ids,
+        contradiction_type: "logical".to_string(),
+        severity: severity.to_string(),
+        formal_proof,
+        human_explanation: format!(
+            "The propositions [{}] are jointly unsatisfiable: compiling their formal \
+             expressions to CNF and running DPLL finds no assignment of truth values \
+             that satisfies all of them at once, and removing any one of them restores \
+             satisfiability.",
+            statements.join("; ")
+        ),
+        proof,
+    })
 }
 
-/// Parse an implication "X → Y" from a formal expression.
-fn parse_implication(expr: &str) -> Option<(String, String)> {
-    // Try "→" first, then "->"
-    let arrow_patterns = ["→", "->"];
-    for arrow in &arrow_patterns {
-        if let Some(pos) = expr.find(arrow) {
-            let lhs = expr[..pos].trim().to_string();
-            let rhs = expr[pos + arrow.len()..].trim().to_string();
-            if !lhs.is_empty() && !rhs.is_empty() {
-                return Some((lhs, rhs));
+/// A minimal unsatisfiable core — the irreducible subset of propositions
+/// found by [`minimal_unsat_core`] — paired with the resolution refutation
+/// that proves it, so a caller can report not just *that* a set of
+/// statements is jointly unsatisfiable but the smallest group responsible
+/// and the exact derivation chain to `⊥`.
+pub(crate) struct ProofTree {
+    pub(crate) core_proposition_ids: Vec<String>,
+    pub(crate) steps: Vec<crate::types::ProofStep>,
+}
+
+/// Deletion-based minimization of an UNSAT clause set: starting from every
+/// proposition that contributed a clause, repeatedly try dropping one
+/// (removing all its clauses) and re-solving with DPLL; keep it dropped
+/// only if the remaining clause set is still UNSAT. The fixpoint is
+/// irreducible — every remaining proposition is necessary for the
+/// conflict, since dropping any one of them would make the rest
+/// satisfiable.
+///
+/// `clauses` must already be known unsatisfiable over the full set; this
+/// function doesn't re-check that itself. `candidate_order` fixes both the
+/// deletion order (callers typically try non-load-bearing propositions
+/// first, so the surviving core favors keeping load-bearing claims intact)
+/// and, combined with the final sort, makes the result deterministic.
+///
+/// Bounded by `MAX_CORE_CANDIDATES` deletion attempts so a pathologically
+/// large conflict set can't turn one contradiction report into thousands of
+/// DPLL calls — candidates past the cap are left in the core untried, so
+/// the result may not be fully minimal in that case, but is still a valid
+/// (if possibly non-irreducible) unsatisfiable core.
+pub(crate) fn minimal_unsat_core(
+    clauses: &[Clause],
+    var_index: &HashMap<String, usize>,
+    candidate_order: &[String],
+) -> ProofTree {
+    const MAX_CORE_CANDIDATES: usize = 500;
+    let num_vars = var_index.len();
+
+    let mut core: HashSet<String> = clauses.iter().map(|c| c.source_prop.clone()).collect();
+
+    for candidate in candidate_order.iter().take(MAX_CORE_CANDIDATES) {
+        let mut trial = core.clone();
+        trial.remove(candidate);
+        let trial_literals: Vec<Vec<i32>> =
+            clauses.iter().filter(|c| trial.contains(&c.source_prop)).map(|c| c.literals.clone()).collect();
+        if !dpll_satisfiable(&trial_literals, num_vars) {
+            core = trial;
+        }
+    }
+
+    let mut core_proposition_ids: Vec<String> = core.into_iter().collect();
+    core_proposition_ids.sort();
+
+    let core_clauses: Vec<&Clause> =
+        clauses.iter().filter(|c| core_proposition_ids.contains(&c.source_prop)).collect();
+    let var_names: HashMap<usize, String> = var_index.iter().map(|(name, id)| (*id, name.clone())).collect();
+    let steps = resolution_refutation(&core_clauses, &var_names).unwrap_or_default();
+
+    ProofTree { core_proposition_ids, steps }
+}
+
+/// A clause discovered while searching for a resolution proof, tagged with
+/// how it was obtained: either a premise straight from CNF compilation, or
+/// the result of resolving two earlier clauses on a pivot variable.
+struct ResolutionNode {
+    literals: Vec<i32>,
+    justification: Justification,
+}
+
+enum Justification {
+    Premise(String),
+    Resolvent { left: usize, right: usize, pivot: i32 },
+}
+
+/// Run resolution saturation over `clauses` — already known to be jointly
+/// unsatisfiable — until the empty clause `⊥` is derived, mirroring how a
+/// resolution-based ATP closes a refutation. Each round resolves every pair
+/// of clauses on every complementary literal and keeps resolvents not seen
+/// before; since the underlying variable set is finite, this is guaranteed
+/// to terminate, and resolution is refutation-complete for propositional
+/// logic so an UNSAT input always yields `⊥` eventually.
+///
+/// Bounded by `MAX_NODES` so a large or pathological core can't blow up the
+/// search; returns `None` in that case rather than hang, and the caller
+/// falls back to a one-line textual proof over the core.
+fn resolution_refutation(
+    clauses: &[&Clause],
+    var_names: &HashMap<usize, String>,
+) -> Option<Vec<crate::types::ProofStep>> {
+    const MAX_NODES: usize = 4000;
+
+    let mut nodes: Vec<ResolutionNode> = clauses
+        .iter()
+        .map(|c| {
+            let mut literals = c.literals.clone();
+            literals.sort_unstable();
+            literals.dedup();
+            ResolutionNode { literals, justification: Justification::Premise(c.source_prop.clone()) }
+        })
+        .collect();
+    let mut seen: HashSet<Vec<i32>> = nodes.iter().map(|n| n.literals.clone()).collect();
+    let mut empty_index = nodes.iter().position(|n| n.literals.is_empty());
+
+    while empty_index.is_none() {
+        let before = nodes.len();
+        'round: for i in 0..before {
+            for j in (i + 1)..before {
+                let pivots: Vec<i32> = nodes[i]
+                    .literals
+                    .iter()
+                    .filter(|&&lit| nodes[j].literals.contains(&-lit))
+                    .map(|&lit| lit.abs())
+                    .collect();
+                for pivot in pivots {
+                    let mut resolvent: Vec<i32> = nodes[i]
+                        .literals
+                        .iter()
+                        .chain(nodes[j].literals.iter())
+                        .filter(|&&lit| lit != pivot && lit != -pivot)
+                        .cloned()
+                        .collect();
+                    resolvent.sort_unstable();
+                    resolvent.dedup();
+                    // A resolvent containing both a literal and its negation
+                    // is a tautology and can never help close the proof.
+                    if resolvent.iter().any(|lit| resolvent.contains(&-lit)) {
+                        continue;
+                    }
+                    if !seen.insert(resolvent.clone()) {
+                        continue;
+                    }
+                    let is_empty = resolvent.is_empty();
+                    nodes.push(ResolutionNode {
+                        literals: resolvent,
+                        justification: Justification::Resolvent { left: i, right: j, pivot },
+                    });
+                    if is_empty {
+                        empty_index = Some(nodes.len() - 1);
+                        break 'round;
+                    }
+                    if nodes.len() >= MAX_NODES {
+                        return None;
+                    }
+                }
             }
         }
+        if nodes.len() == before {
+            // Saturated without deriving ⊥ — shouldn't happen for a
+            // genuinely UNSAT input, but bail out rather than loop forever.
+            return None;
+        }
+    }
+
+    let empty_index = empty_index?;
+
+    // Prune to just the ancestors of the final step. Every resolvent's
+    // index is greater than both its parents', so the ancestor set visited
+    // in descending order is already a valid reverse-topological walk.
+    let mut needed: Vec<usize> = Vec::new();
+    let mut stack = vec![empty_index];
+    let mut visited: HashSet<usize> = HashSet::new();
+    while let Some(idx) = stack.pop() {
+        if !visited.insert(idx) {
+            continue;
+        }
+        needed.push(idx);
+        if let Justification::Resolvent { left, right, .. } = &nodes[idx].justification {
+            stack.push(*left);
+            stack.push(*right);
+        }
+    }
+    needed.sort_unstable();
+
+    let step_number: HashMap<usize, usize> =
+        needed.iter().enumerate().map(|(step, &idx)| (idx, step + 1)).collect();
+
+    Some(
+        needed
+            .iter()
+            .map(|&idx| {
+                let node = &nodes[idx];
+                let step = step_number[&idx];
+                let clause = render_clause(&node.literals, var_names);
+                let (justification, parents) = match &node.justification {
+                    Justification::Premise(prop_id) => (format!("premise ({})", prop_id), Vec::new()),
+                    Justification::Resolvent { left, right, pivot } => (
+                        format!(
+                            "resolution on {}",
+                            var_names.get(&(*pivot as usize)).cloned().unwrap_or_else(|| pivot.to_string())
+                        ),
+                        vec![step_number[left], step_number[right]],
+                    ),
+                };
+                crate::types::ProofStep { step, clause, justification, parents }
+            })
+            .collect(),
+    )
+}
+
+/// Render a clause's DIMACS-encoded literals as "⊥" (empty) or a
+/// disjunction like "(¬A ∨ B)", substituting variable names back in for
+/// their internal ids.
+fn render_clause(literals: &[i32], var_names: &HashMap<usize, String>) -> String {
+    if literals.is_empty() {
+        return "⊥".to_string();
+    }
+    let rendered: Vec<String> = literals
+        .iter()
+        .map(|lit| {
+            let name = var_names
+                .get(&(lit.unsigned_abs() as usize))
+                .cloned()
+                .unwrap_or_else(|| lit.unsigned_abs().to_string());
+            if *lit < 0 { format!("¬{}", name) } else { name }
+        })
+        .collect();
+    if rendered.len() == 1 {
+        rendered.into_iter().next().unwrap()
+    } else {
+        format!("({})", rendered.join(" ∨ "))
     }
-    None
 }
 
-/// Detect resource/numeric contradictions (e.g., savings vs expenses math).
+/// Render a full derivation as a numbered chain of (clause, justification,
+/// parents) lines terminating in `⊥`.
+fn render_derivation(proof: &[crate::types::ProofStep]) -> String {
+    proof
+        .iter()
+        .map(|step| {
+            if step.parents.is_empty() {
+                format!("{}. {} — {}", step.step, step.clause, step.justification)
+            } else {
+                format!(
+                    "{}. {} — {} (from steps {})",
+                    step.step,
+                    step.clause,
+                    step.justification,
+                    step.parents.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", ")
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Detect resource/numeric contradictions by running Fourier–Motzkin
+/// elimination over every linear constraint parsable from the graph's
+/// propositions (e.g. `savings ≥ 6 * monthly_burn`, `monthly_burn = 8000`,
+/// `savings = 80000`) — a real decision procedure for linear arithmetic over
+/// the rationals, rather than pattern-matching on "sufficient" wording.
 fn detect_resource_contradictions(
     graph: &LogicalGraph,
     contradictions: &mut Vec<Contradiction>,
     counter: &mut u32,
 ) {
-    // Look for propositions that define numeric values for related variables
-    // e.g., "savings = $80K" and "expenses = $8K/month" with "savings sufficient" assumption
-    let numeric_props: Vec<(&crate::types::Proposition, f64)> = graph
+    let constraints: Vec<LinearConstraint> = graph
         .propositions
         .iter()
-        .filter_map(|p| {
-            extract_numeric_value(&p.formal_expression)
-                .or_else(|| extract_numeric_value(&p.statement))
-                .map(|v| (p, v))
+        .flat_map(|p| {
+            parse_linear_constraint(&p.formal_expression, &p.id)
+                .or_else(|| parse_linear_constraint(&p.statement, &p.id))
+                .unwrap_or_default()
         })
         .collect();
 
-    // Check for "sufficient" assumptions that don't hold under the numbers
-    for p in &graph.propositions {
-        if p.prop_type == "assumption"
-            && (p.formal_expression.contains("≥")
-                || p.formal_expression.contains(">=")
-                || p.statement.to_lowercase().contains("sufficient"))
-        {
-            // Find numeric constraints this assumption depends on
-            let deps_from = graph.get_relationships_from(&p.id);
-            let deps_to = graph.get_relationships_to(&p.id);
-
-            let related_ids: Vec<&str> = deps_from
-                .iter()
-                .map(|r| r.to_id.as_str())
-                .chain(deps_to.iter().map(|r| r.from_id.as_str()))
-                .collect();
+    if constraints.len() < 2 {
+        return;
+    }
 
-            let related_nums: Vec<(&crate::types::Proposition, f64)> = numeric_props
-                .iter()
-                .filter(|(prop, _)| related_ids.contains(&prop.id.as_str()))
-                .cloned()
-                .collect();
+    let Some((proof, proposition_ids)) = fourier_motzkin_contradiction(constraints) else {
+        return;
+    };
 
-            if related_nums.len() >= 2 {
-                // Flag as potential resource contradiction — the assumption
-                // may not hold given the numeric constraints
-                *counter += 1;
-                let affected_ids: Vec<String> = std::iter::once(p.id.clone())
-                    .chain(related_nums.iter().map(|(prop, _)| prop.id.clone()))
-                    .collect();
+    *counter += 1;
+    let load_bearing = proposition_ids
+        .iter()
+        .filter_map(|id| graph.get_proposition(id))
+        .any(|p| p.is_load_bearing);
+    let statements: Vec<String> = proposition_ids
+        .iter()
+        .filter_map(|id| graph.get_proposition(id))
+        .map(|p| format!("\"{}\"", p.statement))
+        .collect();
 
-                contradictions.push(Contradiction {
-                    id: format!("contradiction-resource-{}", counter),
-                    proposition_ids: affected_ids,
-                    contradiction_type: "empirical".to_string(),
-                    severity: if p.is_load_bearing { "critical" } else { "major" }.to_string(),
-                    formal_proof: format!(
-                        "{} — requires verification against numeric constraints",
-                        p.formal_expression
-                    ),
-                    human_explanation: format!(
-                        "The assumption \"{}\" may not hold when checked against the actual numbers: {}. \
-                         Verify that the math supports this claim.",
-                        p.statement,
-                        related_nums
-                            .iter()
-                            .map(|(prop, val)| format!("\"{}\" ({})", prop.statement, val))
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    ),
+    contradictions.push(Contradiction {
+        id: format!("contradiction-resource-{}", counter),
+        proposition_ids,
+        contradiction_type: "empirical".to_string(),
+        severity: if load_bearing { "critical" } else { "major" }.to_string(),
+        formal_proof: render_derivation(&proof),
+        human_explanation: format!(
+            "The numeric constraints in {} are jointly impossible: eliminating every \
+             variable via Fourier–Motzkin leaves an inequality that can never hold.",
+            statements.join(", ")
+        ),
+        proof,
+    });
+}
+
+/// An exact rational number, kept as a reduced numerator/denominator pair
+/// (denominator always positive) so repeated elimination never drifts the
+/// way floating point would.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Rational {
+    num: i64,
+    den: i64,
+}
+
+impl Rational {
+    fn new(num: i64, den: i64) -> Self {
+        let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+        let g = gcd(num, den);
+        Rational { num: num / g, den: den / g }
+    }
+
+    fn from_int(n: i64) -> Self {
+        Rational { num: n, den: 1 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    fn is_positive(&self) -> bool {
+        self.num > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.num < 0
+    }
+
+    fn neg(&self) -> Self {
+        Rational { num: -self.num, den: self.den }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Rational::new(self.num * other.den + other.num * self.den, self.den * other.den)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Rational::new(self.num * other.num, self.den * other.den)
+    }
+}
+
+impl std::fmt::Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    if a == 0 { 1 } else { a }
+}
+
+/// A linear constraint `Σ cᵢ·xᵢ {≤,<} bound`, normalized so every comparison
+/// operator (`≥`, `>`, `=`) collapses to one of these two canonical forms —
+/// `strict` distinguishes `<` from `≤`. `source_props` tracks which
+/// propositions' constraints contributed, surviving through elimination so
+/// the final contradiction can cite exactly the ones implicated.
+#[derive(Clone)]
+struct LinearConstraint {
+    coeffs: HashMap<String, Rational>,
+    bound: Rational,
+    strict: bool,
+    source_props: Vec<String>,
+}
+
+/// Parse every linear (in)equality found in `expr` into one or two
+/// `LinearConstraint`s (an equality splits into a `≤` and a `≥` half).
+/// Returns `None` when `expr` doesn't contain a recognizable comparison
+/// between a linear combination of named variables and a constant, e.g.
+/// plain boolean propositions fall through untouched.
+fn parse_linear_constraint(expr: &str, source_prop: &str) -> Option<Vec<LinearConstraint>> {
+    let normalized = expr.replace(">=", "≥").replace("<=", "≤");
+    let ops = ["≥", "≤", "=", ">", "<"];
+    let (pos, op) = ops.iter().find_map(|&op| normalized.find(op).map(|pos| (pos, op)))?;
+
+    let lhs = &normalized[..pos];
+    let rhs = &normalized[pos + op.len()..];
+    let (lhs_coeffs, lhs_const) = parse_affine_side(&tokenize(lhs))?;
+    let (rhs_coeffs, rhs_const) = parse_affine_side(&tokenize(rhs))?;
+    if lhs_coeffs.is_empty() && rhs_coeffs.is_empty() {
+        return None;
+    }
+
+    let mut coeffs = lhs_coeffs;
+    for (name, c) in rhs_coeffs {
+        let entry = coeffs.entry(name).or_insert(Rational::from_int(0));
+        *entry = entry.sub(&c);
+    }
+    coeffs.retain(|_, c| !c.is_zero());
+    let bound = rhs_const.sub(&lhs_const);
+
+    let mk = |coeffs: HashMap<String, Rational>, bound: Rational, strict: bool| LinearConstraint {
+        coeffs,
+        bound,
+        strict,
+        source_props: vec![source_prop.to_string()],
+    };
+
+    Some(match op {
+        "≤" => vec![mk(coeffs, bound, false)],
+        "<" => vec![mk(coeffs, bound, true)],
+        "≥" => vec![mk(negate_coeffs(&coeffs), bound.neg(), false)],
+        ">" => vec![mk(negate_coeffs(&coeffs), bound.neg(), true)],
+        "=" => vec![mk(coeffs.clone(), bound, false), mk(negate_coeffs(&coeffs), bound.neg(), false)],
+        _ => return None,
+    })
+}
+
+fn negate_coeffs(coeffs: &HashMap<String, Rational>) -> HashMap<String, Rational> {
+    coeffs.iter().map(|(k, v)| (k.clone(), v.neg())).collect()
+}
+
+/// Split one side of a comparison (e.g. `"savings - 6 * monthly_burn"`) into
+/// its per-variable coefficients and constant term.
+fn parse_affine_side(tokens: &[String]) -> Option<(HashMap<String, Rational>, Rational)> {
+    if tokens.is_empty() {
+        return None;
+    }
+    let mut coeffs: HashMap<String, Rational> = HashMap::new();
+    let mut constant = Rational::from_int(0);
+    let mut sign = 1i64;
+    let mut i = 0;
+    while i < tokens.len() {
+        match tokens[i].as_str() {
+            "+" => {
+                sign = 1;
+                i += 1;
+                continue;
+            }
+            "-" => {
+                sign = -1;
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        let (coeff, var, consumed) = parse_term(&tokens[i..])?;
+        i += consumed;
+        let signed = coeff.mul(&Rational::from_int(sign));
+        match var {
+            Some(name) => {
+                let entry = coeffs.entry(name).or_insert(Rational::from_int(0));
+                *entry = entry.add(&signed);
+            }
+            None => constant = constant.add(&signed),
+        }
+        sign = 1;
+    }
+    Some((coeffs, constant))
+}
+
+/// Parse one term — `NUM`, `VAR`, `NUM * VAR`, or `VAR * NUM` — from the
+/// front of `tokens`, returning its coefficient, optional variable name, and
+/// how many tokens it consumed.
+fn parse_term(tokens: &[String]) -> Option<(Rational, Option<String>, usize)> {
+    let first = tokens.first()?;
+    if let Some(num) = parse_number(first) {
+        if tokens.get(1).map(String::as_str) == Some("*") {
+            let var = tokens.get(2)?;
+            if is_identifier(var) {
+                return Some((num, Some(var.clone()), 3));
+            }
+        }
+        return Some((num, None, 1));
+    }
+    if is_identifier(first) {
+        if tokens.get(1).map(String::as_str) == Some("*") {
+            if let Some(num) = tokens.get(2).and_then(|t| parse_number(t)) {
+                return Some((num, Some(first.clone()), 3));
+            }
+        }
+        return Some((Rational::from_int(1), Some(first.clone()), 1));
+    }
+    None
+}
+
+fn is_identifier(tok: &str) -> bool {
+    tok.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && tok.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Parse a numeric literal, accepting `$`, thousands separators, a decimal
+/// point, and a `K`/`M` suffix (e.g. `"$80,000"`, `"8000"`, `"6"`).
+fn parse_number(tok: &str) -> Option<Rational> {
+    let mut s = tok.replace(['$', ','], "");
+    let multiplier = if s.ends_with(['K', 'k']) {
+        s.pop();
+        1000
+    } else if s.ends_with(['M', 'm']) {
+        s.pop();
+        1_000_000
+    } else {
+        1
+    };
+    if s.is_empty() {
+        return None;
+    }
+    if let Some(dot) = s.find('.') {
+        let int_part = &s[..dot];
+        let frac_part = &s[dot + 1..];
+        if !frac_part.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let negative = int_part.starts_with('-');
+        let digits = int_part.trim_start_matches('-');
+        let int_val: i64 = if digits.is_empty() { 0 } else { digits.parse().ok()? };
+        let frac_val: i64 = if frac_part.is_empty() { 0 } else { frac_part.parse().ok()? };
+        let den = 10i64.pow(frac_part.len() as u32);
+        let magnitude = int_val * den + frac_val;
+        let num = if negative { -magnitude } else { magnitude };
+        Some(Rational::new(num * multiplier, den))
+    } else {
+        let val: i64 = s.parse().ok()?;
+        Some(Rational::from_int(val * multiplier))
+    }
+}
+
+/// Tokenize an arithmetic expression, splitting out `+`, `-`, `*` as their
+/// own tokens (identifiers and numbers may otherwise abut them, e.g.
+/// `"6*monthly_burn"`).
+fn tokenize(expr: &str) -> Vec<String> {
+    let mut spaced = String::new();
+    for ch in expr.chars() {
+        if ch == '+' || ch == '-' || ch == '*' {
+            spaced.push(' ');
+            spaced.push(ch);
+            spaced.push(' ');
+        } else {
+            spaced.push(ch);
+        }
+    }
+    spaced.split_whitespace().map(str::to_string).collect()
+}
+
+enum EliminationOrigin {
+    Premise(String),
+    Eliminated { left: usize, right: usize, var: String },
+}
+
+struct EliminationNode {
+    constraint: LinearConstraint,
+    origin: EliminationOrigin,
+}
+
+/// Run Fourier–Motzkin elimination over `constraints`: repeatedly pick a
+/// variable, pair every constraint with a positive coefficient on it against
+/// every constraint with a negative coefficient, and combine each pair into
+/// a new constraint free of that variable. A constraint mentioning a
+/// variable on only one side projects out harmlessly (it bounds that
+/// variable in one direction only, so it can't itself cause UNSAT). Once
+/// every variable is gone, any surviving constant-only constraint of the
+/// form `0 < 0` or similar proves the original system unsatisfiable.
+///
+/// Returns the derivation trail (as `ProofStep`s, pruned to just the
+/// ancestors of the impossible constraint) and the proposition ids behind
+/// its premises, or `None` if the system is satisfiable or the search
+/// exceeds `MAX_NODES`.
+fn fourier_motzkin_contradiction(
+    constraints: Vec<LinearConstraint>,
+) -> Option<(Vec<crate::types::ProofStep>, Vec<String>)> {
+    const MAX_NODES: usize = 2000;
+
+    let mut nodes: Vec<EliminationNode> = Vec::new();
+    let mut frontier: Vec<usize> = Vec::new();
+    for c in constraints {
+        let prop = c.source_props.first().cloned().unwrap_or_default();
+        nodes.push(EliminationNode { constraint: c, origin: EliminationOrigin::Premise(prop) });
+        frontier.push(nodes.len() - 1);
+    }
+
+    let mut vars: Vec<String> = {
+        let mut set: HashSet<String> = HashSet::new();
+        for &idx in &frontier {
+            set.extend(nodes[idx].constraint.coeffs.keys().cloned());
+        }
+        let mut v: Vec<String> = set.into_iter().collect();
+        v.sort();
+        v
+    };
+
+    let mut violated = frontier
+        .iter()
+        .copied()
+        .find(|&idx| nodes[idx].constraint.coeffs.is_empty() && is_violated(&nodes[idx].constraint));
+
+    while violated.is_none() {
+        let Some(var) = vars.pop() else { break };
+        let mut next_frontier = Vec::new();
+        let mut pos = Vec::new();
+        let mut neg = Vec::new();
+        for &idx in &frontier {
+            match nodes[idx].constraint.coeffs.get(&var) {
+                Some(c) if c.is_positive() => pos.push(idx),
+                Some(c) if c.is_negative() => neg.push(idx),
+                _ => next_frontier.push(idx),
+            }
+        }
+
+        'eliminate: for &p in &pos {
+            for &n in &neg {
+                let combined = combine_eliminating(&nodes[p].constraint, &nodes[n].constraint, &var);
+                nodes.push(EliminationNode {
+                    constraint: combined,
+                    origin: EliminationOrigin::Eliminated { left: p, right: n, var: var.clone() },
                 });
+                let new_idx = nodes.len() - 1;
+                next_frontier.push(new_idx);
+                if nodes[new_idx].constraint.coeffs.is_empty() && is_violated(&nodes[new_idx].constraint) {
+                    violated = Some(new_idx);
+                    break 'eliminate;
+                }
+                if nodes.len() >= MAX_NODES {
+                    return None;
+                }
             }
         }
+        frontier = next_frontier;
     }
+
+    let violated = violated?;
+
+    let mut needed: Vec<usize> = Vec::new();
+    let mut stack = vec![violated];
+    let mut visited: HashSet<usize> = HashSet::new();
+    while let Some(idx) = stack.pop() {
+        if !visited.insert(idx) {
+            continue;
+        }
+        needed.push(idx);
+        if let EliminationOrigin::Eliminated { left, right, .. } = &nodes[idx].origin {
+            stack.push(*left);
+            stack.push(*right);
+        }
+    }
+    needed.sort_unstable();
+    let step_number: HashMap<usize, usize> =
+        needed.iter().enumerate().map(|(step, &idx)| (idx, step + 1)).collect();
+
+    let mut proposition_ids: Vec<String> = Vec::new();
+    for &idx in &needed {
+        if let EliminationOrigin::Premise(prop_id) = &nodes[idx].origin {
+            if !proposition_ids.contains(prop_id) {
+                proposition_ids.push(prop_id.clone());
+            }
+        }
+    }
+    proposition_ids.sort();
+
+    let proof = needed
+        .iter()
+        .map(|&idx| {
+            let node = &nodes[idx];
+            let step = step_number[&idx];
+            let clause = render_inequality(&node.constraint);
+            let (justification, parents) = match &node.origin {
+                EliminationOrigin::Premise(prop_id) => (format!("premise ({})", prop_id), Vec::new()),
+                EliminationOrigin::Eliminated { left, right, var } => {
+                    (format!("eliminate {}", var), vec![step_number[left], step_number[right]])
+                }
+            };
+            crate::types::ProofStep { step, clause, justification, parents }
+        })
+        .collect();
+
+    Some((proof, proposition_ids))
 }
 
-/// Extract a numeric value from text (e.g., "$80,000" → 80000, "$8K" → 8000).
-fn extract_numeric_value(text: &str) -> Option<f64> {
-    let text = text.replace(',', "");
-    // Look for $N, $NK, $NM patterns
-    for word in text.split_whitespace() {
-        let cleaned = word.trim_matches(|c: char| !c.is_ascii_digit() && c != '.' && c != 'K' && c != 'k' && c != 'M' && c != 'm');
-        if cleaned.is_empty() {
+/// A constant-only constraint (no remaining variables) is impossible when
+/// its bound can never satisfy `0 {≤,<} bound`.
+fn is_violated(c: &LinearConstraint) -> bool {
+    if c.strict { !c.bound.is_positive() } else { c.bound.is_negative() }
+}
+
+/// Combine `p` (positive coefficient on `var`) and `n` (negative coefficient
+/// on `var`) by scaling each to cancel `var` and summing — the one
+/// Fourier–Motzkin elimination step. The combination is strict iff either
+/// parent was.
+fn combine_eliminating(p: &LinearConstraint, n: &LinearConstraint, var: &str) -> LinearConstraint {
+    let a = p.coeffs[var];
+    let neg_b = n.coeffs[var].neg();
+
+    let mut coeffs: HashMap<String, Rational> = HashMap::new();
+    for (name, c) in &p.coeffs {
+        if name == var {
             continue;
         }
-        let multiplier = if cleaned.ends_with('K') || cleaned.ends_with('k') {
-            1000.0
-        } else if cleaned.ends_with('M') || cleaned.ends_with('m') {
-            1_000_000.0
-        } else {
-            1.0
-        };
-        let num_part = cleaned.trim_end_matches(|c: char| c == 'K' || c == 'k' || c == 'M' || c == 'm');
-        if let Ok(val) = num_part.parse::<f64>() {
-            return Some(val * multiplier);
+        let entry = coeffs.entry(name.clone()).or_insert(Rational::from_int(0));
+        *entry = entry.add(&c.mul(&neg_b));
+    }
+    for (name, c) in &n.coeffs {
+        if name == var {
+            continue;
         }
+        let entry = coeffs.entry(name.clone()).or_insert(Rational::from_int(0));
+        *entry = entry.add(&c.mul(&a));
     }
-    None
+    coeffs.retain(|_, c| !c.is_zero());
+
+    let mut source_props = p.source_props.clone();
+    for s in &n.source_props {
+        if !source_props.contains(s) {
+            source_props.push(s.clone());
+        }
+    }
+
+    LinearConstraint {
+        coeffs,
+        bound: p.bound.mul(&neg_b).add(&n.bound.mul(&a)),
+        strict: p.strict || n.strict,
+        source_props,
+    }
+}
+
+/// Render a linear constraint as `"savings - 6·monthly_burn ≤ 0"`, sorting
+/// variables alphabetically for a stable, readable rendering.
+fn render_inequality(c: &LinearConstraint) -> String {
+    let mut names: Vec<&String> = c.coeffs.keys().collect();
+    names.sort();
+    let terms: Vec<String> = names
+        .iter()
+        .filter(|name| !c.coeffs[**name].is_zero())
+        .map(|name| format!("{}·{}", c.coeffs[*name], name))
+        .collect();
+    let lhs = if terms.is_empty() { "0".to_string() } else { terms.join(" + ").replace("+ -", "- ") };
+    format!("{} {} {}", lhs, if c.strict { "<" } else { "≤" }, c.bound)
+}
+
+/// Above this many candidates, `minimal_contradiction_core` falls back to
+/// greedy single-deletion instead of exact search — bounds worst-case work
+/// the same way `minimal_unsat_core`'s `MAX_CORE_CANDIDATES` does.
+const MAX_EXACT_CORE_CANDIDATES: usize = 20;
+
+/// Compute the minimal set of propositions whose retraction clears every
+/// reported contradiction — a minimum hitting set over each contradiction's
+/// `proposition_ids`, unlike [`minimal_unsat_core`], which minimizes a single
+/// already-isolated unsatisfiable clause set. This instead spans the full,
+/// merged `Vec<Contradiction>` `detect_contradictions` returns (explicit
+/// edges, temporal, boolean, and resource conflicts together), so a
+/// proposition implicated in more than one contradiction only needs to
+/// appear once in the result.
+///
+/// At or below `MAX_EXACT_CORE_CANDIDATES` candidates, this is solved
+/// exactly: try every hitting-set size `k` from 0 upward and return the
+/// first one found, so the result is a true minimum, not merely irreducible.
+/// Candidates are tried in an order that prefers non-load-bearing
+/// propositions, so among same-size hitting sets the one found first favors
+/// retracting those over load-bearing claims.
+///
+/// Above the cap, falls back to `greedy_irreducible_core`'s deletion-based
+/// minimization, which is still irreducible (removing any further id would
+/// leave some contradiction unresolved) but, like `minimal_unsat_core`
+/// beyond its own cap, not guaranteed to be the smallest possible core.
+pub fn minimal_contradiction_core(
+    graph: &LogicalGraph,
+    contradictions: &[Contradiction],
+) -> Vec<String> {
+    let universe: HashSet<String> =
+        contradictions.iter().flat_map(|c| c.proposition_ids.iter().cloned()).collect();
+    if universe.is_empty() {
+        return Vec::new();
+    }
+
+    let mut candidates: Vec<String> = universe.into_iter().collect();
+    candidates.sort_by(|a, b| {
+        let a_load_bearing = graph.get_proposition(a).map(|p| p.is_load_bearing).unwrap_or(false);
+        let b_load_bearing = graph.get_proposition(b).map(|p| p.is_load_bearing).unwrap_or(false);
+        a_load_bearing.cmp(&b_load_bearing).then_with(|| a.cmp(b))
+    });
+
+    if candidates.len() <= MAX_EXACT_CORE_CANDIDATES {
+        for k in 0..=candidates.len() {
+            if let Some(mut core) = hitting_set_of_size(contradictions, &candidates, k) {
+                core.sort();
+                return core;
+            }
+        }
+    }
+
+    greedy_irreducible_core(contradictions, &candidates)
+}
+
+/// Backtracking search for a hitting set of exactly `k` candidates (tried in
+/// `candidates` order), pruning as soon as too few candidates remain to
+/// reach `k`. Returns the first one found, or `None` if no size-`k` subset
+/// hits every contradiction.
+fn hitting_set_of_size(
+    contradictions: &[Contradiction],
+    candidates: &[String],
+    k: usize,
+) -> Option<Vec<String>> {
+    fn search(
+        contradictions: &[Contradiction],
+        candidates: &[String],
+        k: usize,
+        start: usize,
+        chosen: &mut Vec<String>,
+    ) -> bool {
+        if chosen.len() == k {
+            return contradictions
+                .iter()
+                .all(|c| c.proposition_ids.iter().any(|id| chosen.contains(id)));
+        }
+        if candidates.len() - start < k - chosen.len() {
+            return false; // not enough candidates left to reach size k
+        }
+        for i in start..candidates.len() {
+            chosen.push(candidates[i].clone());
+            if search(contradictions, candidates, k, i + 1, chosen) {
+                return true;
+            }
+            chosen.pop();
+        }
+        false
+    }
+
+    let mut chosen = Vec::with_capacity(k);
+    if search(contradictions, candidates, k, 0, &mut chosen) {
+        Some(chosen)
+    } else {
+        None
+    }
+}
+
+/// Deletion-based minimization, the fallback for candidate sets too large
+/// for `minimal_contradiction_core` to search exactly: try dropping each
+/// candidate (in order — non-load-bearing first), and keep it dropped only
+/// if every contradiction still has at least one surviving member. The
+/// fixpoint is irreducible — removing any further id would leave some
+/// contradiction with no active proposition, i.e. unresolved — but, since
+/// dropped candidates are never reconsidered, not guaranteed to be the
+/// smallest such set.
+fn greedy_irreducible_core(contradictions: &[Contradiction], candidates: &[String]) -> Vec<String> {
+    let mut core: HashSet<String> = candidates.iter().cloned().collect();
+
+    for candidate in candidates {
+        let mut without_candidate = core.clone();
+        without_candidate.remove(candidate);
+
+        let still_resolves_everything = contradictions
+            .iter()
+            .all(|c| c.proposition_ids.iter().any(|id| without_candidate.contains(id)));
+
+        if still_resolves_everything {
+            core = without_candidate;
+        }
+    }
+
+    let mut result: Vec<String> = core.into_iter().collect();
+    result.sort();
+    result
 }
 
 #[cfg(test)]
@@ -404,6 +1256,23 @@ mod tests {
         assert!(result[0].proposition_ids.contains(&"p2".to_string()));
     }
 
+    #[test]
+    fn test_temporal_contradiction_via_allen_relations() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.formal_expression = "before(research, launch)".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "after(research, launch)".to_string();
+
+        let graph = make_graph(vec![p0, p1], vec![]);
+        let result = detect_contradictions(&graph);
+
+        assert_eq!(result.len(), 1, "Expected one temporal contradiction, got {:?}", result);
+        assert_eq!(result[0].contradiction_type, "temporal");
+        assert!(result[0].proposition_ids.contains(&"p0".to_string()));
+        assert!(result[0].proposition_ids.contains(&"p1".to_string()));
+        assert!(!result[0].proof.is_empty());
+    }
+
     #[test]
     fn test_no_contradictions() {
         let graph = make_graph(
@@ -419,12 +1288,17 @@ mod tests {
 
     #[test]
     fn test_logical_implication_conflict() {
+        // "growth → success" and "growth → ¬success" alone are satisfiable
+        // (just set growth false) — a genuine contradiction also needs
+        // something asserting growth itself.
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
         let mut p1 = make_prop("p1", "claim", "high");
         p1.formal_expression = "growth → success".to_string();
         let mut p2 = make_prop("p2", "claim", "high");
         p2.formal_expression = "growth → ¬success".to_string();
 
-        let graph = make_graph(vec![p1, p2], vec![]);
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
         let result = detect_contradictions(&graph);
         assert!(result.len() >= 1, "Should detect logical implication conflict");
         assert!(
@@ -443,9 +1317,11 @@ mod tests {
         p3.formal_expression = "X → Y".to_string();
         let mut p4 = make_prop("p4", "claim", "high");
         p4.formal_expression = "X → ¬Y".to_string();
+        let mut p5 = make_prop("p5", "evidence", "high");
+        p5.formal_expression = "X".to_string();
 
         let graph = make_graph(
-            vec![p1, p2, p3, p4],
+            vec![p1, p2, p3, p4, p5],
             vec![make_rel("r1", "p2", "p1", "contradicts")],
         );
 
@@ -454,11 +1330,186 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_duration() {
-        assert!(extract_duration("> 12 months").is_some());
-        assert!(extract_duration("takes > 6 months").is_some());
-        let (op, val) = extract_duration("> 12 months").unwrap();
-        assert_eq!(op, ">");
-        assert!((val - 12.0).abs() < 0.01);
+    fn test_boolean_unsat_core_excludes_unrelated_propositions() {
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "growth → success".to_string();
+        let mut p2 = make_prop("p2", "claim", "high");
+        p2.formal_expression = "growth → ¬success".to_string();
+        let unrelated = make_prop("p3", "evidence", "high"); // default "p3 → true"
+
+        let graph = make_graph(vec![p0, p1, p2, unrelated], vec![]);
+        let result = detect_contradictions(&graph);
+        let boolean = result
+            .iter()
+            .find(|c| c.id.starts_with("contradiction-boolean"))
+            .expect("should find a DPLL-derived contradiction");
+        assert!(!boolean.proposition_ids.contains(&"p3".to_string()));
+        assert!(boolean.proposition_ids.contains(&"p0".to_string()));
+        assert!(boolean.proposition_ids.contains(&"p1".to_string()));
+        assert!(boolean.proposition_ids.contains(&"p2".to_string()));
+    }
+
+    #[test]
+    fn test_minimal_unsat_core_is_deterministic_and_irreducible() {
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "growth → success".to_string();
+        let mut p2 = make_prop("p2", "claim", "high");
+        p2.formal_expression = "growth → ¬success".to_string();
+
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
+        let (clauses, var_index) = compile_to_cnf(&graph.propositions);
+        let mut candidates: Vec<String> = clauses.iter().map(|c| c.source_prop.clone()).collect();
+        candidates.sort();
+        candidates.dedup();
+
+        let tree = minimal_unsat_core(&clauses, &var_index, &candidates);
+        assert_eq!(tree.core_proposition_ids, vec!["p0".to_string(), "p1".to_string(), "p2".to_string()]);
+        assert!(!tree.steps.is_empty(), "a resolution refutation should have been found");
+
+        // Re-running over the same input reaches the same core.
+        let tree_again = minimal_unsat_core(&clauses, &var_index, &candidates);
+        assert_eq!(tree.core_proposition_ids, tree_again.core_proposition_ids);
+    }
+
+    #[test]
+    fn test_minimal_unsat_core_respects_the_candidate_cap() {
+        // Candidates never tried for deletion stay in the core, as those past
+        // `MAX_CORE_CANDIDATES` do; with no candidates at all, every
+        // contributing proposition is left in place untried.
+        let mut p0 = make_prop("p0", "evidence", "high");
+        p0.formal_expression = "growth".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "growth → success".to_string();
+        let mut p2 = make_prop("p2", "claim", "high");
+        p2.formal_expression = "growth → ¬success".to_string();
+
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
+        let (clauses, var_index) = compile_to_cnf(&graph.propositions);
+
+        // An empty candidate list means nothing is ever tried for deletion.
+        let tree = minimal_unsat_core(&clauses, &var_index, &[]);
+        assert_eq!(tree.core_proposition_ids, vec!["p0".to_string(), "p1".to_string(), "p2".to_string()]);
+    }
+
+    #[test]
+    fn test_boolean_satisfiable_without_shared_assertion() {
+        // "X → Y" and "X → ¬Y" without anything asserting X is satisfiable.
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "X → Y".to_string();
+        let mut p2 = make_prop("p2", "claim", "high");
+        p2.formal_expression = "X → ¬Y".to_string();
+
+        let graph = make_graph(vec![p1, p2], vec![]);
+        let result = detect_contradictions(&graph);
+        assert!(
+            !result.iter().any(|c| c.id.starts_with("contradiction-boolean")),
+            "Should not flag a contradiction when the antecedent is never asserted"
+        );
+    }
+
+    #[test]
+    fn test_resource_contradiction_insufficient_savings() {
+        let mut p0 = make_prop("p0", "assumption", "medium");
+        p0.formal_expression = "savings ≥ 10 * monthly_burn".to_string();
+        let mut p1 = make_prop("p1", "evidence", "high");
+        p1.formal_expression = "monthly_burn = 8000".to_string();
+        let mut p2 = make_prop("p2", "evidence", "high");
+        p2.formal_expression = "savings = 40000".to_string();
+
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
+        let result = detect_contradictions(&graph);
+
+        assert_eq!(result.len(), 1, "Expected one resource contradiction, got {:?}", result);
+        assert_eq!(result[0].contradiction_type, "empirical");
+        assert!(result[0].proposition_ids.contains(&"p0".to_string()));
+        assert!(result[0].proposition_ids.contains(&"p1".to_string()));
+        assert!(result[0].proposition_ids.contains(&"p2".to_string()));
+        assert!(!result[0].proof.is_empty());
+    }
+
+    #[test]
+    fn test_resource_no_contradiction_when_numbers_add_up() {
+        let mut p0 = make_prop("p0", "assumption", "medium");
+        p0.formal_expression = "savings ≥ 6 * monthly_burn".to_string();
+        let mut p1 = make_prop("p1", "evidence", "high");
+        p1.formal_expression = "monthly_burn = 8000".to_string();
+        let mut p2 = make_prop("p2", "evidence", "high");
+        p2.formal_expression = "savings = 80000".to_string();
+
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
+        let result = detect_contradictions(&graph);
+
+        assert!(result.is_empty(), "80000 ≥ 6*8000 holds; nothing should be flagged");
+    }
+
+    #[test]
+    fn test_parse_linear_constraint_splits_equality_into_two_bounds() {
+        let constraints = parse_linear_constraint("savings = 80000", "p0").unwrap();
+        assert_eq!(constraints.len(), 2);
+        assert!(constraints.iter().all(|c| c.coeffs.contains_key("savings")));
+    }
+
+    fn make_contradiction(id: &str, props: &[&str]) -> Contradiction {
+        Contradiction {
+            id: id.to_string(),
+            proposition_ids: props.iter().map(|s| s.to_string()).collect(),
+            contradiction_type: "logical".to_string(),
+            severity: "major".to_string(),
+            formal_proof: "test".to_string(),
+            human_explanation: "test".to_string(),
+            proof: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_minimal_core_shared_node_resolves_both() {
+        // Two contradictions share "B" — retracting just B clears both.
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "assumption", "medium"),
+                make_prop("C", "claim", "high"),
+            ],
+            vec![],
+        );
+        let contradictions = vec![
+            make_contradiction("c1", &["A", "B"]),
+            make_contradiction("c2", &["B", "C"]),
+        ];
+        let core = minimal_contradiction_core(&graph, &contradictions);
+        assert_eq!(core, vec!["B".to_string()]);
+    }
+
+    #[test]
+    fn test_minimal_core_disjoint_contradictions_needs_one_each() {
+        let graph = make_graph(
+            vec![
+                make_prop("A", "claim", "high"),
+                make_prop("B", "claim", "high"),
+                make_prop("C", "claim", "high"),
+                make_prop("D", "claim", "high"),
+            ],
+            vec![],
+        );
+        let contradictions = vec![
+            make_contradiction("c1", &["A", "B"]),
+            make_contradiction("c2", &["C", "D"]),
+        ];
+        let core = minimal_contradiction_core(&graph, &contradictions);
+        // One representative from each disjoint pair must remain.
+        assert!(core.len() == 2, "Expected one id per disjoint contradiction, got {:?}", core);
+        assert!(core.contains(&"A".to_string()) || core.contains(&"B".to_string()));
+        assert!(core.contains(&"C".to_string()) || core.contains(&"D".to_string()));
+    }
+
+    #[test]
+    fn test_minimal_core_no_contradictions_is_empty() {
+        let graph = make_graph(vec![], vec![]);
+        let core = minimal_contradiction_core(&graph, &[]);
+        assert!(core.is_empty());
     }
 }
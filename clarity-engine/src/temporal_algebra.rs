@@ -0,0 +1,464 @@
+use crate::types::{LogicalGraph, ProofStep};
+use std::collections::{HashMap, HashSet};
+
+/// One of Allen's 13 qualitative relations between two time intervals,
+/// recognized from a proposition's `formal_expression` written as
+/// `relation(a, b)` — e.g. `before(rebuild, launch)` or
+/// `overlaps(research, development)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AllenRelation {
+    Before,
+    After,
+    Meets,
+    MetBy,
+    Overlaps,
+    OverlappedBy,
+    Starts,
+    StartedBy,
+    During,
+    Contains,
+    Finishes,
+    FinishedBy,
+    Equals,
+}
+
+impl AllenRelation {
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        use AllenRelation::*;
+        Some(match keyword {
+            "before" => Before,
+            "after" => After,
+            "meets" => Meets,
+            "met_by" | "metby" => MetBy,
+            "overlaps" => Overlaps,
+            "overlapped_by" | "overlappedby" => OverlappedBy,
+            "starts" => Starts,
+            "started_by" | "startedby" => StartedBy,
+            "during" => During,
+            "contains" => Contains,
+            "finishes" => Finishes,
+            "finished_by" | "finishedby" => FinishedBy,
+            "equals" | "equal" => Equals,
+            _ => return None,
+        })
+    }
+}
+
+/// Parse `relation(a, b)` out of a formal expression, e.g.
+/// `"before(rebuild, launch)"` → `(Before, "rebuild", "launch")`. Returns
+/// `None` for any expression that isn't written in this form, so ordinary
+/// boolean propositions pass through untouched.
+fn parse_allen_relation(expr: &str) -> Option<(AllenRelation, String, String)> {
+    let expr = expr.trim();
+    let open = expr.find('(')?;
+    let close = expr.rfind(')')?;
+    if close <= open {
+        return None;
+    }
+    let keyword = expr[..open].trim().to_lowercase().replace('-', "_");
+    let relation = AllenRelation::from_keyword(&keyword)?;
+    let mut args = expr[open + 1..close].splitn(2, ',');
+    let a = args.next()?.trim().to_string();
+    let b = args.next()?.trim().to_string();
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    Some((relation, a, b))
+}
+
+// ── Point algebra ──
+//
+// Rather than hand-transcribe Allen's 13×13 interval composition table —
+// easy to get subtly wrong, and hard for a reviewer to check against a
+// decade-old paper — each interval is split into a `start` and `end` point,
+// and every Allen relation is translated into point-to-point orderings.
+// Point algebra has only 3 base relations (`<`, `=`, `>`), so its
+// composition table is small enough to state, and verify, directly. The
+// interval network's consistency reduces exactly to this point network's
+// consistency.
+
+const LT: u8 = 0b001;
+const EQ: u8 = 0b010;
+const GT: u8 = 0b100;
+const FULL: u8 = LT | EQ | GT;
+
+fn converse(rel: u8) -> u8 {
+    let mut out = rel & EQ;
+    if rel & LT != 0 {
+        out |= GT;
+    }
+    if rel & GT != 0 {
+        out |= LT;
+    }
+    out
+}
+
+/// Compose two base point relations: given `a r1 b` and `b r2 c`, what can
+/// be said about `a` vs `c`? A `<` and a `>` in sequence leave no
+/// information — every other pairing is forced.
+fn compose_base(r1: u8, r2: u8) -> u8 {
+    match (r1, r2) {
+        (LT, LT) | (LT, EQ) | (EQ, LT) => LT,
+        (EQ, EQ) => EQ,
+        (EQ, GT) | (GT, EQ) | (GT, GT) => GT,
+        (LT, GT) | (GT, LT) => FULL,
+        _ => unreachable!("compose_base is only ever called with single-bit relations"),
+    }
+}
+
+/// Compose two (possibly disjunctive) point relation sets by unioning the
+/// composition of every pair of base relations they contain.
+fn compose_set(r1: u8, r2: u8) -> u8 {
+    let mut out = 0u8;
+    for b1 in [LT, EQ, GT] {
+        if r1 & b1 == 0 {
+            continue;
+        }
+        for b2 in [LT, EQ, GT] {
+            if r2 & b2 == 0 {
+                continue;
+            }
+            out |= compose_base(b1, b2);
+        }
+    }
+    out
+}
+
+fn render_point_rel(rel: u8) -> String {
+    let symbols: Vec<&str> = [(LT, "<"), (EQ, "="), (GT, ">")]
+        .into_iter()
+        .filter(|&(bit, _)| rel & bit != 0)
+        .map(|(_, s)| s)
+        .collect();
+    match symbols.len() {
+        1 => symbols[0].to_string(),
+        _ => format!("{{{}}}", symbols.join(",")),
+    }
+}
+
+fn start_of(interval: &str) -> String {
+    format!("{}:start", interval)
+}
+
+fn end_of(interval: &str) -> String {
+    format!("{}:end", interval)
+}
+
+/// The point constraints a given Allen relation imposes between `a`'s and
+/// `b`'s endpoints — exactly the textbook definition of each relation.
+fn point_constraints(rel: AllenRelation, a: &str, b: &str) -> Vec<(String, String, u8)> {
+    use AllenRelation::*;
+    let (s1, e1, s2, e2) = (start_of(a), end_of(a), start_of(b), end_of(b));
+    match rel {
+        Before => vec![(e1, s2, LT)],
+        After => vec![(s1, e2, GT)],
+        Meets => vec![(e1, s2, EQ)],
+        MetBy => vec![(e2, s1, EQ)],
+        Overlaps => vec![(s1, s2.clone(), LT), (s2, e1.clone(), LT), (e1, e2, LT)],
+        OverlappedBy => vec![(s2, s1.clone(), LT), (s1, e2.clone(), LT), (e2, e1, LT)],
+        Starts => vec![(s1, s2, EQ), (e1, e2, LT)],
+        StartedBy => vec![(s1, s2, EQ), (e2, e1, LT)],
+        During => vec![(s2, s1, LT), (e1, e2, LT)],
+        Contains => vec![(s1, s2, LT), (e2, e1, LT)],
+        Finishes => vec![(e1, e2, EQ), (s2, s1, LT)],
+        FinishedBy => vec![(e1, e2, EQ), (s1, s2, LT)],
+        Equals => vec![(s1, s2, EQ), (e1, e2, EQ)],
+    }
+}
+
+fn canonical_pair(a: &str, b: &str) -> (bool, (String, String)) {
+    if a <= b { (false, (a.to_string(), b.to_string())) } else { (true, (b.to_string(), a.to_string())) }
+}
+
+enum PointOrigin {
+    /// `start(x) < end(x)` for every named interval — not asserted by any
+    /// proposition, just the structural fact that an interval has positive
+    /// duration.
+    Invariant(String),
+    /// An Allen relation asserted directly by a proposition, with nothing
+    /// narrower already known for this point pair.
+    Premise(String),
+    /// A proposition's assertion intersected with an already-known value
+    /// for this point pair (e.g. two propositions both constraining the
+    /// same two intervals).
+    Refined { prev: usize, prop_id: String },
+    /// Tightened by path consistency: composing the relation through `k`
+    /// excluded some possibility the direct relation hadn't already.
+    Composed { left: usize, right: usize, prev: Option<usize>, pivot: String },
+}
+
+struct PointNode {
+    key: (String, String),
+    relation: u8,
+    origin: PointOrigin,
+}
+
+fn lookup(current: &HashMap<(String, String), usize>, nodes: &[PointNode], a: &str, b: &str) -> (Option<usize>, u8) {
+    let (swapped, key) = canonical_pair(a, b);
+    match current.get(&key) {
+        Some(&idx) => {
+            let rel = nodes[idx].relation;
+            (Some(idx), if swapped { converse(rel) } else { rel })
+        }
+        None => (None, FULL),
+    }
+}
+
+/// Build a point-algebra constraint network from every Allen relation
+/// parsable out of the graph's propositions, then run path consistency —
+/// for every triple of points, tighten the direct relation by intersecting
+/// it with the composition of the relations through the third point — to a
+/// fixpoint. Returns the derivation trail ending in an impossible (empty)
+/// relation between two points, and the proposition ids behind its
+/// premises, when the asserted timing relationships are jointly
+/// inconsistent; `None` if they're consistent (or too sparse to say
+/// anything, or the network is larger than `MAX_NODES` can bound).
+pub fn detect_temporal_contradiction(graph: &LogicalGraph) -> Option<(Vec<ProofStep>, Vec<String>)> {
+    const MAX_NODES: usize = 2000;
+
+    let mut assertions: Vec<(String, String, u8, String)> = Vec::new();
+    let mut interval_names: HashSet<String> = HashSet::new();
+    for p in &graph.propositions {
+        if let Some((relation, a, b)) = parse_allen_relation(&p.formal_expression) {
+            interval_names.insert(a.clone());
+            interval_names.insert(b.clone());
+            for (pa, pb, rel) in point_constraints(relation, &a, &b) {
+                assertions.push((pa, pb, rel, p.id.clone()));
+            }
+        }
+    }
+    if assertions.len() < 2 {
+        return None;
+    }
+
+    let mut nodes: Vec<PointNode> = Vec::new();
+    let mut current: HashMap<(String, String), usize> = HashMap::new();
+    let mut violated: Option<usize> = None;
+
+    let mut interval_names: Vec<String> = interval_names.into_iter().collect();
+    interval_names.sort();
+    for name in &interval_names {
+        let (swapped, key) = canonical_pair(&start_of(name), &end_of(name));
+        let relation = if swapped { converse(LT) } else { LT };
+        nodes.push(PointNode { key: key.clone(), relation, origin: PointOrigin::Invariant(name.clone()) });
+        current.insert(key, nodes.len() - 1);
+    }
+
+    'premises: for (a, b, rel, prop_id) in assertions {
+        let (swapped, key) = canonical_pair(&a, &b);
+        let incoming = if swapped { converse(rel) } else { rel };
+        let node = match current.get(&key) {
+            Some(&prev_idx) => {
+                let narrowed = nodes[prev_idx].relation & incoming;
+                PointNode { key: key.clone(), relation: narrowed, origin: PointOrigin::Refined { prev: prev_idx, prop_id } }
+            }
+            None => PointNode { key: key.clone(), relation: incoming, origin: PointOrigin::Premise(prop_id) },
+        };
+        let is_empty = node.relation == 0;
+        nodes.push(node);
+        let idx = nodes.len() - 1;
+        current.insert(key, idx);
+        if is_empty {
+            violated = Some(idx);
+            break 'premises;
+        }
+        if nodes.len() >= MAX_NODES {
+            return None;
+        }
+    }
+
+    if violated.is_none() {
+        'path_consistency: loop {
+            let mut changed = false;
+            for i in &interval_names {
+                for point_i in [start_of(i), end_of(i)] {
+                    for j in &interval_names {
+                        for point_j in [start_of(j), end_of(j)] {
+                            if point_i == point_j {
+                                continue;
+                            }
+                            for k in &interval_names {
+                                for point_k in [start_of(k), end_of(k)] {
+                                    if point_k == point_i || point_k == point_j {
+                                        continue;
+                                    }
+                                    let (rik_idx, rik_rel) = lookup(&current, &nodes, &point_i, &point_k);
+                                    let (rkj_idx, rkj_rel) = lookup(&current, &nodes, &point_k, &point_j);
+                                    let composed = compose_set(rik_rel, rkj_rel);
+                                    if composed == FULL {
+                                        continue;
+                                    }
+                                    let (ij_idx, ij_rel) = lookup(&current, &nodes, &point_i, &point_j);
+                                    let narrowed = ij_rel & composed;
+                                    if narrowed == ij_rel {
+                                        continue;
+                                    }
+                                    let (swapped, key) = canonical_pair(&point_i, &point_j);
+                                    let canon_rel = if swapped { converse(narrowed) } else { narrowed };
+                                    nodes.push(PointNode {
+                                        key: key.clone(),
+                                        relation: canon_rel,
+                                        origin: PointOrigin::Composed {
+                                            left: rik_idx.expect("composed != FULL implies both sides are constrained"),
+                                            right: rkj_idx.expect("composed != FULL implies both sides are constrained"),
+                                            prev: ij_idx,
+                                            pivot: point_k.clone(),
+                                        },
+                                    });
+                                    let idx = nodes.len() - 1;
+                                    current.insert(key, idx);
+                                    changed = true;
+                                    if canon_rel == 0 {
+                                        violated = Some(idx);
+                                        break 'path_consistency;
+                                    }
+                                    if nodes.len() >= MAX_NODES {
+                                        return None;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    let violated = violated?;
+
+    let mut needed: Vec<usize> = Vec::new();
+    let mut stack = vec![violated];
+    let mut visited: HashSet<usize> = HashSet::new();
+    while let Some(idx) = stack.pop() {
+        if !visited.insert(idx) {
+            continue;
+        }
+        needed.push(idx);
+        match &nodes[idx].origin {
+            PointOrigin::Invariant(_) | PointOrigin::Premise(_) => {}
+            PointOrigin::Refined { prev, .. } => stack.push(*prev),
+            PointOrigin::Composed { left, right, prev, .. } => {
+                stack.push(*left);
+                stack.push(*right);
+                if let Some(p) = prev {
+                    stack.push(*p);
+                }
+            }
+        }
+    }
+    needed.sort_unstable();
+    let step_number: HashMap<usize, usize> = needed.iter().enumerate().map(|(i, &idx)| (idx, i + 1)).collect();
+
+    let mut proposition_ids: Vec<String> = Vec::new();
+    for &idx in &needed {
+        let prop_id = match &nodes[idx].origin {
+            PointOrigin::Premise(id) | PointOrigin::Refined { prop_id: id, .. } => Some(id),
+            PointOrigin::Invariant(_) | PointOrigin::Composed { .. } => None,
+        };
+        if let Some(id) = prop_id {
+            if !proposition_ids.contains(id) {
+                proposition_ids.push(id.clone());
+            }
+        }
+    }
+    proposition_ids.sort();
+
+    let proof = needed
+        .iter()
+        .map(|&idx| {
+            let node = &nodes[idx];
+            let step = step_number[&idx];
+            let clause = if node.relation == 0 {
+                "⊥".to_string()
+            } else {
+                format!("{} {} {}", node.key.0, render_point_rel(node.relation), node.key.1)
+            };
+            let (justification, parents) = match &node.origin {
+                PointOrigin::Invariant(name) => (format!("invariant ({} has positive duration)", name), Vec::new()),
+                PointOrigin::Premise(prop_id) => (format!("premise ({})", prop_id), Vec::new()),
+                PointOrigin::Refined { prev, prop_id } => (format!("premise ({})", prop_id), vec![step_number[prev]]),
+                PointOrigin::Composed { left, right, prev, pivot } => {
+                    let mut parents = vec![step_number[left], step_number[right]];
+                    if let Some(p) = prev {
+                        parents.push(step_number[p]);
+                    }
+                    (format!("path-consistency via {}", pivot), parents)
+                }
+            };
+            ProofStep { step, clause, justification, parents }
+        })
+        .collect();
+
+    Some((proof, proposition_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_parse_allen_relation_extracts_keyword_and_args() {
+        let (relation, a, b) = parse_allen_relation("before(rebuild, launch)").unwrap();
+        assert_eq!(relation, AllenRelation::Before);
+        assert_eq!(a, "rebuild");
+        assert_eq!(b, "launch");
+    }
+
+    #[test]
+    fn test_parse_allen_relation_rejects_non_relation_expressions() {
+        assert!(parse_allen_relation("growth → success").is_none());
+    }
+
+    #[test]
+    fn test_consistent_relations_yield_no_contradiction() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.formal_expression = "before(research, launch)".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "overlaps(research, development)".to_string();
+        let graph = make_graph(vec![p0, p1], vec![]);
+
+        assert!(detect_temporal_contradiction(&graph).is_none());
+    }
+
+    #[test]
+    fn test_direct_conflicting_assertions_on_same_pair() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.formal_expression = "before(rebuild, launch)".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "after(rebuild, launch)".to_string();
+        let graph = make_graph(vec![p0, p1], vec![]);
+
+        let (proof, proposition_ids) = detect_temporal_contradiction(&graph).unwrap();
+        assert!(!proof.is_empty());
+        assert!(proposition_ids.contains(&"p0".to_string()));
+        assert!(proposition_ids.contains(&"p1".to_string()));
+    }
+
+    #[test]
+    fn test_transitive_contradiction_detected_via_path_consistency() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.formal_expression = "before(a, b)".to_string();
+        let mut p1 = make_prop("p1", "claim", "high");
+        p1.formal_expression = "before(b, c)".to_string();
+        let mut p2 = make_prop("p2", "claim", "high");
+        p2.formal_expression = "after(a, c)".to_string();
+        let graph = make_graph(vec![p0, p1, p2], vec![]);
+
+        let (proof, proposition_ids) = detect_temporal_contradiction(&graph).unwrap();
+        assert!(!proof.is_empty());
+        assert_eq!(proposition_ids, vec!["p0".to_string(), "p1".to_string(), "p2".to_string()]);
+    }
+
+    #[test]
+    fn test_fewer_than_two_relations_is_not_enough_to_conflict() {
+        let mut p0 = make_prop("p0", "claim", "high");
+        p0.formal_expression = "before(rebuild, launch)".to_string();
+        let graph = make_graph(vec![p0], vec![]);
+
+        assert!(detect_temporal_contradiction(&graph).is_none());
+    }
+}
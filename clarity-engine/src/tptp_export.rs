@@ -0,0 +1,411 @@
+use crate::sat_solver::{atom_name, parse_implication, split_conjuncts};
+use crate::types::LogicalGraph;
+use std::collections::HashMap;
+
+/// One `fof(name, role, formula).` line of the exported document, tagged
+/// with the graph node (proposition or relationship) it came from so a
+/// prover's derivation — which only mentions formula names — can be traced
+/// back to graph terms.
+#[derive(Debug, Clone)]
+pub struct TptpFormula {
+    pub name: String,
+    pub node_id: String,
+    pub role: String,
+    pub formula: String,
+}
+
+/// The result of exporting a graph: every formula that was successfully
+/// translated, plus a diagnostic for every proposition that wasn't —
+/// translation failures are skipped rather than aborting the whole export.
+pub struct TptpExport {
+    pub formulas: Vec<TptpFormula>,
+    pub skipped: Vec<String>,
+}
+
+impl TptpExport {
+    /// Render the full TPTP document, one `fof(...).` line per formula.
+    pub fn to_document(&self) -> String {
+        self.formulas
+            .iter()
+            .map(|f| format!("fof({}, {}, {}).", f.name, f.role, f.formula))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Serialize `graph` to TPTP first-order form, with `conjecture_id` as the
+/// proposition to prove — a machine-checked complement to `sat_solver`'s
+/// propositional heuristics, handed off to an external ATP via
+/// [`run_prover`]. `assumption`/`evidence` nodes (and any claim other than
+/// `conjecture_id`) become `axiom`s, `conjecture_id` becomes the single
+/// `conjecture`; `supports`/`depends_on`/`contradicts` edges become further
+/// axioms linking the endpoints' formulas by implication or negated
+/// conjunction.
+pub fn export_to_tptp(graph: &LogicalGraph, conjecture_id: &str) -> TptpExport {
+    let mut formulas = Vec::new();
+    let mut skipped = Vec::new();
+    let mut translated: HashMap<String, String> = HashMap::new();
+
+    for prop in &graph.propositions {
+        match translate_formula(&prop.formal_expression) {
+            Some(formula) => {
+                let role = if prop.id == conjecture_id { "conjecture" } else { "axiom" };
+                let name = format!("prop_{}", sanitize_identifier(&prop.id));
+                translated.insert(prop.id.clone(), formula.clone());
+                formulas.push(TptpFormula { name, node_id: prop.id.clone(), role: role.to_string(), formula });
+            }
+            None => {
+                skipped.push(format!("{}: could not parse formal_expression {:?}", prop.id, prop.formal_expression))
+            }
+        }
+    }
+
+    let mut rel_counter = 0u32;
+    for rel in &graph.relationships {
+        let (Some(from), Some(to)) = (translated.get(&rel.from_id), translated.get(&rel.to_id)) else {
+            continue;
+        };
+        let linked = match rel.rel_type.as_str() {
+            "supports" | "depends_on" => Some(format!("({} => {})", from, to)),
+            "contradicts" => Some(format!("~(({}) & ({}))", from, to)),
+            _ => None,
+        };
+        if let Some(formula) = linked {
+            rel_counter += 1;
+            formulas.push(TptpFormula {
+                name: format!("rel_{}", rel_counter),
+                node_id: rel.id.clone(),
+                role: "axiom".to_string(),
+                formula,
+            });
+        }
+    }
+
+    TptpExport { formulas, skipped }
+}
+
+/// Translate one `formal_expression` into a TPTP FOF formula, reusing the
+/// same "X → Y" / "¬"-or-"!"-negation / "∧"-conjunction grammar
+/// `sat_solver` already parses. `None` for an expression that's empty or
+/// otherwise fails to parse.
+fn translate_formula(expr: &str) -> Option<String> {
+    let expr = expr.trim();
+    if expr.is_empty() {
+        return None;
+    }
+    if let Some((lhs, rhs)) = parse_implication(expr) {
+        let antecedent = translate_conjunction(&lhs)?;
+        let consequent = translate_conjunction(&rhs)?;
+        Some(format!("({} => {})", antecedent, consequent))
+    } else {
+        translate_conjunction(expr)
+    }
+}
+
+fn translate_conjunction(expr: &str) -> Option<String> {
+    let atoms = split_conjuncts(expr);
+    if atoms.is_empty() {
+        return None;
+    }
+    let literals: Vec<String> = atoms.iter().map(|atom| translate_literal(atom)).collect();
+    if literals.len() == 1 {
+        Some(literals.into_iter().next().expect("just checked len == 1"))
+    } else {
+        Some(format!("({})", literals.join(" & ")))
+    }
+}
+
+fn translate_literal(raw: &str) -> String {
+    let negated = raw.trim_start().starts_with('¬') || raw.trim_start().starts_with('!');
+    let name = atom_name(raw);
+    let symbol = match name.as_str() {
+        "true" => "$true".to_string(),
+        "false" => "$false".to_string(),
+        _ => sanitize_identifier(&name),
+    };
+    if negated {
+        format!("~{}", symbol)
+    } else {
+        symbol
+    }
+}
+
+/// Sanitize a graph identifier or atom name into a valid TPTP `lower_word`:
+/// lowercase ASCII letters, digits and underscores, never starting with a
+/// digit.
+pub fn sanitize_identifier(raw: &str) -> String {
+    let mut out: String = raw
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    let starts_with_letter = out.chars().next().map(|c| c.is_ascii_alphabetic()).unwrap_or(false);
+    if !starts_with_letter {
+        out.insert(0, 'n');
+    }
+    out
+}
+
+/// Which SZS ontology outcome a prover reported for the conjecture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SzsStatus {
+    /// The axioms entail the conjecture.
+    Theorem,
+    /// The premises have a model where the conjecture is false.
+    CounterSatisfiable,
+    /// The prover couldn't decide within its resource limits.
+    Unknown,
+    /// The prover was killed by its own timeout before deciding.
+    Timeout,
+    /// Any other SZS status word, or none found at all.
+    Other(String),
+}
+
+/// A prover's verdict on one export: its SZS status, whether that amounts to
+/// the conjecture being entailed, and which graph nodes participated in the
+/// refutation (empty unless the prover's output contains a `CNFRefutation`
+/// block; filled from that block whatever the status).
+pub struct ProverReport {
+    pub status: SzsStatus,
+    pub entailed: bool,
+    pub refutation_nodes: Vec<String>,
+}
+
+/// Parse a prover's raw stdout: the `% SZS status ...` line determines
+/// [`ProverReport::entailed`], and — when present — the `% SZS output start
+/// CNFRefutation` / `... end CNFRefutation` block is scanned for the
+/// formula names this export assigned, mapped back to their node ids.
+pub fn parse_prover_output(output: &str, export: &TptpExport) -> ProverReport {
+    let status = parse_szs_status(output);
+    let entailed = status == SzsStatus::Theorem;
+
+    let mut refutation_nodes: Vec<String> = Vec::new();
+    if let Some(block) = extract_cnf_refutation_block(output) {
+        let tokens: std::collections::HashSet<&str> =
+            block.split(|c: char| !c.is_ascii_alphanumeric() && c != '_').filter(|t| !t.is_empty()).collect();
+        for formula in &export.formulas {
+            if tokens.contains(formula.name.as_str()) && !refutation_nodes.contains(&formula.node_id) {
+                refutation_nodes.push(formula.node_id.clone());
+            }
+        }
+        refutation_nodes.sort();
+    }
+
+    ProverReport { status, entailed, refutation_nodes }
+}
+
+fn parse_szs_status(output: &str) -> SzsStatus {
+    const MARKER: &str = "SZS status ";
+    for line in output.lines() {
+        if let Some(pos) = line.find(MARKER) {
+            let word = line[pos + MARKER.len()..].split_whitespace().next().unwrap_or("");
+            return match word {
+                "Theorem" => SzsStatus::Theorem,
+                "CounterSatisfiable" => SzsStatus::CounterSatisfiable,
+                "Unknown" => SzsStatus::Unknown,
+                "Timeout" => SzsStatus::Timeout,
+                other => SzsStatus::Other(other.to_string()),
+            };
+        }
+    }
+    SzsStatus::Other("no SZS status line found".to_string())
+}
+
+fn extract_cnf_refutation_block(output: &str) -> Option<String> {
+    let start = output.find("% SZS output start CNFRefutation")?;
+    let end = output[start..].find("% SZS output end CNFRefutation").map(|rel| start + rel).unwrap_or(output.len());
+    Some(output[start..end].to_string())
+}
+
+/// Run a configured ATP binary over an export's TPTP document and parse its
+/// verdict. Not available on wasm: there's no process to shell out to in a
+/// browser, so this is a native-only capability (callers embedding
+/// `clarity-engine` in a CLI or test harness, not the JS-facing `analyze`
+/// entry point).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_prover(binary: &str, args: &[&str], export: &TptpExport) -> Result<ProverReport, String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let document = export.to_document();
+    let mut child = Command::new(binary)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to launch prover {:?}: {}", binary, e))?;
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| "prover stdin was not piped".to_string())?
+        .write_all(document.as_bytes())
+        .map_err(|e| format!("failed to write TPTP input to prover: {}", e))?;
+
+    let output = child.wait_with_output().map_err(|e| format!("prover {:?} exited abnormally: {}", binary, e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_prover_output(&stdout, export))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    #[test]
+    fn test_sanitize_identifier_lowercases_and_replaces_invalid_chars() {
+        assert_eq!(sanitize_identifier("Prop-1"), "prop_1");
+        assert_eq!(sanitize_identifier("growth rate"), "growth_rate");
+    }
+
+    #[test]
+    fn test_sanitize_identifier_prefixes_a_leading_digit() {
+        assert_eq!(sanitize_identifier("1claim"), "n1claim");
+        assert_eq!(sanitize_identifier(""), "n");
+    }
+
+    #[test]
+    fn test_export_bare_atom_as_axiom() {
+        let mut prop = make_prop("E", "evidence", "high");
+        prop.formal_expression = "growth".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+        assert_eq!(export.formulas.len(), 1);
+        assert_eq!(export.formulas[0].role, "axiom");
+        assert_eq!(export.formulas[0].formula, "growth");
+        assert!(export.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_export_implication_and_conjecture_role() {
+        let mut prop = make_prop("C", "claim", "high");
+        prop.formal_expression = "growth → success".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+        assert_eq!(export.formulas[0].role, "conjecture");
+        assert_eq!(export.formulas[0].formula, "(growth => success)");
+    }
+
+    #[test]
+    fn test_export_negation_and_conjunction() {
+        let mut prop = make_prop("C", "claim", "high");
+        prop.formal_expression = "a ∧ ¬b → c".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+        assert_eq!(export.formulas[0].formula, "((a & ~b) => c)");
+    }
+
+    #[test]
+    fn test_export_true_literal_becomes_szs_true_constant() {
+        let prop = make_prop("E", "evidence", "high"); // formal_expression defaults to "E → true"
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+        assert_eq!(export.formulas[0].formula, "(e => $true)");
+    }
+
+    #[test]
+    fn test_export_skips_empty_formal_expression_with_diagnostic() {
+        let mut prop = make_prop("E", "evidence", "high");
+        prop.formal_expression = "   ".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+        assert!(export.formulas.is_empty());
+        assert_eq!(export.skipped.len(), 1);
+        assert!(export.skipped[0].contains('E'));
+    }
+
+    #[test]
+    fn test_export_supports_edge_links_formulas_with_implication() {
+        let mut e = make_prop("E", "evidence", "high");
+        e.formal_expression = "e_fact".to_string();
+        let mut c = make_prop("C", "claim", "high");
+        c.formal_expression = "c_fact".to_string();
+        let graph = make_graph(vec![e, c], vec![make_rel("r1", "E", "C", "supports")]);
+        let export = export_to_tptp(&graph, "C");
+        let rel_formula = export.formulas.iter().find(|f| f.node_id == "r1").unwrap();
+        assert_eq!(rel_formula.formula, "(e_fact => c_fact)");
+    }
+
+    #[test]
+    fn test_export_contradicts_edge_links_formulas_with_negated_conjunction() {
+        let mut a = make_prop("A", "claim", "high");
+        a.formal_expression = "a_fact".to_string();
+        let mut b = make_prop("B", "claim", "high");
+        b.formal_expression = "b_fact".to_string();
+        let graph = make_graph(vec![a, b], vec![make_rel("r1", "A", "B", "contradicts")]);
+        let export = export_to_tptp(&graph, "A");
+        let rel_formula = export.formulas.iter().find(|f| f.node_id == "r1").unwrap();
+        assert_eq!(rel_formula.formula, "~((a_fact) & (b_fact))");
+    }
+
+    #[test]
+    fn test_export_skips_edge_whose_endpoint_failed_to_translate() {
+        let mut e = make_prop("E", "evidence", "high");
+        e.formal_expression = "".to_string();
+        let mut c = make_prop("C", "claim", "high");
+        c.formal_expression = "c_fact".to_string();
+        let graph = make_graph(vec![e, c], vec![make_rel("r1", "E", "C", "supports")]);
+        let export = export_to_tptp(&graph, "C");
+        assert!(!export.formulas.iter().any(|f| f.node_id == "r1"));
+    }
+
+    #[test]
+    fn test_to_document_renders_one_fof_line_per_formula() {
+        let mut prop = make_prop("C", "claim", "high");
+        prop.formal_expression = "growth".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+        assert_eq!(export.to_document(), "fof(prop_c, conjecture, growth).");
+    }
+
+    #[test]
+    fn test_parse_szs_status_recognizes_each_known_outcome() {
+        assert_eq!(parse_szs_status("% SZS status Theorem for foo"), SzsStatus::Theorem);
+        assert_eq!(parse_szs_status("% SZS status CounterSatisfiable for foo"), SzsStatus::CounterSatisfiable);
+        assert_eq!(parse_szs_status("% SZS status Unknown for foo"), SzsStatus::Unknown);
+        assert_eq!(parse_szs_status("% SZS status Timeout for foo"), SzsStatus::Timeout);
+        assert_eq!(parse_szs_status("nothing relevant here"), SzsStatus::Other("no SZS status line found".to_string()));
+    }
+
+    #[test]
+    fn test_parse_prover_output_maps_refutation_names_back_to_node_ids() {
+        let mut prop = make_prop("C", "claim", "high");
+        prop.formal_expression = "growth".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+
+        let output = "% SZS status Theorem for export\n\
+                       % SZS output start CNFRefutation\n\
+                       cnf(c1, plain, ($false), inference(resolution, [status(thm)], [prop_c])).\n\
+                       % SZS output end CNFRefutation\n";
+        let report = parse_prover_output(output, &export);
+        assert_eq!(report.status, SzsStatus::Theorem);
+        assert!(report.entailed);
+        assert_eq!(report.refutation_nodes, vec!["C".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_prover_output_without_refutation_block_has_no_nodes() {
+        let export = TptpExport { formulas: Vec::new(), skipped: Vec::new() };
+        let report = parse_prover_output("% SZS status CounterSatisfiable for export\n", &export);
+        assert_eq!(report.status, SzsStatus::CounterSatisfiable);
+        assert!(!report.entailed);
+        assert!(report.refutation_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_run_prover_invokes_binary_and_parses_its_stdout() {
+        // `cat` isn't an ATP, so there's no SZS line in its output (which is
+        // just the TPTP document echoed back) — this exercises the actual
+        // spawn/stdin/stdout plumbing without depending on a real prover
+        // being installed.
+        let mut prop = make_prop("C", "claim", "high");
+        prop.formal_expression = "growth".to_string();
+        let graph = make_graph(vec![prop], vec![]);
+        let export = export_to_tptp(&graph, "C");
+
+        let report = run_prover("cat", &[], &export).expect("cat should be runnable");
+        assert_eq!(report.status, SzsStatus::Other("no SZS status line found".to_string()));
+        assert!(!report.entailed);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::types::{LogicalGraph, PropositionStatus};
+use std::collections::HashMap;
+
+/// Defeasible truth propagation over the argument graph, modeled as a small
+/// Boolean network: `supports`/`depends_on`/`assumes` edges (the same
+/// "dependency" edges `is_dependency_edge` already recognizes) are positive
+/// influence, `contradicts`/`attacks` edges are negative, and a proposition
+/// is "in" (true) iff at least one of its dependency sources is active and
+/// none of its attackers are. Propositions with no incoming dependency edge
+/// — evidence and axioms — are seeded true and never recomputed, so an
+/// attack on one of them has no effect.
+///
+/// `synchronous` selects the update discipline: `true` recomputes every
+/// node's next state from one shared snapshot of the previous round (a
+/// classic synchronous Boolean-network update, where a node can't see its
+/// neighbors' updates until the next round); `false` updates one node at a
+/// time, in topological order, each seeing its neighbors' latest states
+/// within the same round. The two orderings can settle into different fixed
+/// points — or oscillate differently — on the same graph.
+///
+/// Iterates until the labeling repeats (a fixed point) or repeats with
+/// period 2 (an oscillation — the hallmark of a negative-feedback loop, e.g.
+/// a claim that attacks something that in turn supports it). Any node whose
+/// value keeps flipping across that 2-cycle is reported `"oscillating"`
+/// rather than `"defended"`/`"defeated"`.
+pub fn propagate_truth(graph: &LogicalGraph, synchronous: bool) -> Vec<PropositionStatus> {
+    let ids: Vec<String> = graph.propositions.iter().map(|p| p.id.clone()).collect();
+    let axioms: HashMap<String, bool> = ids.iter().map(|id| (id.clone(), is_axiom(graph, id))).collect();
+
+    // Asynchronous sweeps follow topological order so a node tends to see its
+    // supporters' already-updated state within the same round; nodes left out
+    // of the order (stuck in a dependency cycle) are appended at the end.
+    let mut order = crate::graph::topo_sort::topological_sort(graph);
+    for id in &ids {
+        if !order.contains(id) {
+            order.push(id.clone());
+        }
+    }
+
+    let mut state: HashMap<String, bool> = ids.iter().map(|id| (id.clone(), axioms[id])).collect();
+    let mut before_previous: Option<HashMap<String, bool>> = None;
+    let mut oscillating: Vec<String> = Vec::new();
+    let max_iterations = ids.len().saturating_mul(4).max(16);
+
+    for _ in 0..max_iterations {
+        let next = step(graph, &order, &axioms, &state, synchronous);
+        if next == state {
+            break;
+        }
+        if before_previous.as_ref() == Some(&next) {
+            oscillating = ids
+                .iter()
+                .filter(|id| state.get(id.as_str()) != next.get(id.as_str()))
+                .cloned()
+                .collect();
+            break;
+        }
+        before_previous = Some(state);
+        state = next;
+    }
+
+    ids.into_iter()
+        .map(|id| {
+            let status = if oscillating.contains(&id) {
+                "oscillating"
+            } else if state.get(&id).copied().unwrap_or(false) {
+                "defended"
+            } else {
+                "defeated"
+            };
+            PropositionStatus { proposition_id: id, status: status.to_string() }
+        })
+        .collect()
+}
+
+/// A node with no incoming dependency edge has nothing to derive its truth
+/// from, so it's treated as a given (evidence/axiom) rather than computed.
+fn is_axiom(graph: &LogicalGraph, id: &str) -> bool {
+    !graph
+        .get_relationships_to(id)
+        .iter()
+        .any(|r| crate::types::is_dependency_edge(&r.rel_type))
+}
+
+/// Compute one round's next state for every non-axiom node in `order`.
+/// `synchronous` controls whether each node reads the shared `state`
+/// snapshot (old values throughout the round) or the in-progress `working`
+/// map (seeing earlier nodes in `order` already updated this round).
+fn step(
+    graph: &LogicalGraph,
+    order: &[String],
+    axioms: &HashMap<String, bool>,
+    state: &HashMap<String, bool>,
+    synchronous: bool,
+) -> HashMap<String, bool> {
+    if synchronous {
+        let mut next = state.clone();
+        for id in order {
+            if axioms[id] {
+                continue;
+            }
+            next.insert(id.clone(), compute_next(graph, id, state));
+        }
+        next
+    } else {
+        let mut working = state.clone();
+        for id in order {
+            if axioms[id] {
+                continue;
+            }
+            let value = compute_next(graph, id, &working);
+            working.insert(id.clone(), value);
+        }
+        working
+    }
+}
+
+/// A node is "in" iff at least one dependency source is currently active and
+/// no attacker is.
+fn compute_next(graph: &LogicalGraph, id: &str, state: &HashMap<String, bool>) -> bool {
+    let incoming = graph.get_relationships_to(id);
+    let has_active_supporter = incoming.iter().any(|r| {
+        crate::types::is_dependency_edge(&r.rel_type) && state.get(&r.from_id).copied().unwrap_or(false)
+    });
+    let has_active_attacker = incoming.iter().any(|r| {
+        matches!(r.rel_type.as_str(), "contradicts" | "attacks") && state.get(&r.from_id).copied().unwrap_or(false)
+    });
+    has_active_supporter && !has_active_attacker
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::test_helpers::*;
+
+    fn status_of<'a>(labels: &'a [PropositionStatus], id: &str) -> &'a str {
+        &labels.iter().find(|l| l.proposition_id == id).unwrap().status
+    }
+
+    #[test]
+    fn test_axiom_with_no_incoming_dependency_is_defended() {
+        let graph = make_graph(vec![make_prop("E", "evidence", "high")], vec![]);
+        let labels = propagate_truth(&graph, true);
+        assert_eq!(status_of(&labels, "E"), "defended");
+    }
+
+    #[test]
+    fn test_claim_supported_only_by_an_axiom_is_defended() {
+        let graph = make_graph(
+            vec![make_prop("E", "evidence", "high"), make_prop("C", "claim", "high")],
+            vec![make_rel("r1", "E", "C", "supports")],
+        );
+        let labels = propagate_truth(&graph, true);
+        assert_eq!(status_of(&labels, "C"), "defended");
+    }
+
+    #[test]
+    fn test_claim_with_an_active_attacker_is_defeated() {
+        let graph = make_graph(
+            vec![
+                make_prop("E", "evidence", "high"),
+                make_prop("D", "evidence", "high"),
+                make_prop("C", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "E", "C", "supports"),
+                make_rel("r2", "D", "C", "attacks"),
+            ],
+        );
+        let labels = propagate_truth(&graph, true);
+        assert_eq!(status_of(&labels, "C"), "defeated");
+    }
+
+    #[test]
+    fn test_synchronous_negative_feedback_loop_oscillates() {
+        // C (axiom) supports A, and A attacks itself: a textbook NOT-gate
+        // self-loop, which settles into a period-2 flip rather than a fixed point.
+        let graph = make_graph(
+            vec![make_prop("C", "evidence", "high"), make_prop("A", "claim", "high")],
+            vec![
+                make_rel("r1", "C", "A", "supports"),
+                make_rel("r2", "A", "A", "attacks"),
+            ],
+        );
+        let labels = propagate_truth(&graph, true);
+        assert_eq!(status_of(&labels, "C"), "defended");
+        assert_eq!(status_of(&labels, "A"), "oscillating");
+    }
+
+    #[test]
+    fn test_asynchronous_negative_feedback_loop_also_oscillates() {
+        let graph = make_graph(
+            vec![make_prop("C", "evidence", "high"), make_prop("A", "claim", "high")],
+            vec![
+                make_rel("r1", "C", "A", "supports"),
+                make_rel("r2", "A", "A", "attacks"),
+            ],
+        );
+        let labels = propagate_truth(&graph, false);
+        assert_eq!(status_of(&labels, "A"), "oscillating");
+    }
+
+    #[test]
+    fn test_chain_of_claims_all_defended_when_root_is_an_axiom() {
+        let graph = make_graph(
+            vec![
+                make_prop("E", "evidence", "high"),
+                make_prop("A", "claim", "high"),
+                make_prop("B", "claim", "high"),
+            ],
+            vec![
+                make_rel("r1", "E", "A", "supports"),
+                make_rel("r2", "A", "B", "depends_on"),
+            ],
+        );
+        let labels = propagate_truth(&graph, true);
+        assert_eq!(status_of(&labels, "A"), "defended");
+        assert_eq!(status_of(&labels, "B"), "defended");
+    }
+}
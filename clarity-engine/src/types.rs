@@ -20,6 +20,9 @@ pub struct Proposition {
     pub is_implicit: bool,
     pub is_load_bearing: bool,
     pub is_anchored: bool,
+    /// The author/source who asserted this proposition, when known. Lets
+    /// `equivocation_detector` notice a single source arguing both sides.
+    pub source: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -114,6 +117,29 @@ pub struct Contradiction {
     pub severity: String,           // "critical" | "major" | "minor"
     pub formal_proof: String,
     pub human_explanation: String,
+    /// A step-by-step derivation terminating in an impossibility (`⊥`), when
+    /// one was constructed: a resolution refutation for the boolean DPLL
+    /// strategy, a Fourier–Motzkin elimination trail for the numeric
+    /// strategy, or a path-consistency narrowing trail for the temporal
+    /// strategy, all in `sat_solver`/`temporal_algebra`. Empty when the
+    /// contradiction was established some other way. Serializable so a
+    /// downstream consumer can independently re-check each step.
+    pub proof: Vec<ProofStep>,
+}
+
+/// One step in a step-by-step unsatisfiability derivation: either a premise
+/// taken directly from the input, or a step derived by combining two earlier
+/// steps (resolving on a pivot variable, eliminating a variable, or
+/// narrowing a point relation, depending on the strategy that built it). A
+/// chain of these ending in `⊥` proves the underlying constraints can't all
+/// hold at once.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofStep {
+    pub step: usize,
+    pub clause: String,
+    pub justification: String,
+    pub parents: Vec<usize>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -146,6 +172,220 @@ pub struct ArgumentScore {
     pub evidence_paths: u32,
     pub contradiction_count: u32,
     pub vulnerable_assumptions: u32,
+    /// Up to the top 3 strongest, distinct support paths reaching this
+    /// proposition — see `argument_scorer::k_strongest_evidence_paths`.
+    pub strongest_paths: Vec<EvidencePath>,
+}
+
+/// A cluster of propositions whose dependency edges form a strongly-connected
+/// component — i.e. each proposition ultimately depends on itself.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReasoningCycle {
+    pub proposition_ids: Vec<String>,
+}
+
+/// The strongest chain of confidence-weighted support reaching a proposition,
+/// found by multi-hop propagation from grounded evidence.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EvidenceStrength {
+    pub strength: f64,
+    pub path: Vec<String>,
+}
+
+/// One of the top-k strongest, distinct support paths to a claim, as found
+/// by `argument_scorer::k_strongest_evidence_paths`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EvidencePath {
+    pub strength: f64,
+    pub path: Vec<String>,
+}
+
+/// An "opposing camp" — a cluster of propositions that reinforce each other
+/// via supports/depends_on edges and tend to sit across contradictions from
+/// other camps, as found by `graph::community_detection::detect_argument_camps`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Community {
+    pub id: String,
+    pub proposition_ids: Vec<String>,
+}
+
+/// A fact whose truth value was never established by any proposition or
+/// relationship, surfaced while evaluating whether some other proposition
+/// is entailed, refuted, or left unknown (see `entailment_evaluator`).
+/// Rather than silently discarding the unresolved branch, this names
+/// exactly what premise would resolve it — which may itself expose a
+/// contradiction once supplied.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Question {
+    pub target_id: String,
+    pub unbound_atom: String,
+    pub blocking_prop_id: String,
+    pub text: String,
+}
+
+/// A single source/author flagged as arguing both sides of a contradiction —
+/// either by directly authoring two propositions that appear together in a
+/// `Contradiction`, or by authoring two propositions that each `support` a
+/// different claim where those claims themselves contradict each other.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Equivocation {
+    pub source_id: String,
+    pub first_prop: String,
+    pub second_prop: String,
+    pub contradiction_id: String,
+}
+
+/// A minimal belief revision that resolves one contradiction: retracting
+/// every proposition in `retract` (each an `"assumption"`-typed proposition,
+/// the only kind treated as negotiable) makes the contradiction verifiably
+/// disappear under a fresh `detect_contradictions` run, shrunk greedily by
+/// dropping members that turn out unnecessary (not necessarily the smallest
+/// such set), as found by `counterfactual::suggest_retractions`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RetractionSuggestion {
+    pub contradiction_id: String,
+    pub retract: Vec<String>,
+    pub severity: String,
+}
+
+/// One proposition's stable label under `truth_propagation::propagate_truth`'s
+/// Boolean-network dynamics: `"defended"` (reached a fixed point as true —
+/// it has an active supporter and no active attacker), `"defeated"` (fixed
+/// point as false), or `"oscillating"` (never settled — its truth value
+/// keeps flipping under a detected period-2 cycle).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PropositionStatus {
+    pub proposition_id: String,
+    pub status: String,
+}
+
+/// A candidate new `supports` edge (to an already-grounded evidence node)
+/// that would close a `GroundingGap`, as found by `grounding::validate_grounding`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedEdge {
+    pub from_id: String,
+    pub to_id: String,
+}
+
+/// A claim with no path back to grounded evidence, as found by
+/// `grounding::validate_grounding`'s backward reachability search over
+/// dependency edges. `blame_frontier` is the closest ungrounded predecessors
+/// where the support chain actually breaks — not just the claim itself —
+/// and `suggested_supports` are candidate `supports` edges from existing
+/// grounded evidence that would close the gap, when any evidence exists to
+/// point to.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GroundingGap {
+    pub claim_id: String,
+    pub blame_frontier: Vec<String>,
+    pub suggested_supports: Vec<SuggestedEdge>,
+}
+
+/// The classical Dung-style argumentation semantics over a graph's
+/// `assumption` propositions, as found by `aba::compute_extensions`: every
+/// admissible, complete, and stable extension (each a set of accepted
+/// assumption ids — there can be zero, one, or many of each), plus the
+/// single grounded extension every framework has exactly one of.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AbaExtensions {
+    pub admissible: Vec<Vec<String>>,
+    pub complete: Vec<Vec<String>>,
+    pub stable: Vec<Vec<String>>,
+    pub grounded: Vec<String>,
+}
+
+/// Explicit reason/conclusion reconstruction input for `reconstruction::
+/// build_from_reasons`: `source` identifies whoever made the argument (used
+/// as the synthesized propositions' `source` field, so `equivocation_detector`
+/// can still trace them), `reasons` become `evidence` propositions, and
+/// `conclusions` become `claim` propositions every reason is wired to
+/// support.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconstructionInput {
+    pub source: String,
+    pub reasons: Vec<String>,
+    pub conclusions: Vec<String>,
+}
+
+/// A `LogicalGraph` synthesized from semi-structured input by
+/// `reconstruction::build_from_reasons` or `reconstruction::parse_argdown`,
+/// paired with every input line or field that couldn't be turned into a
+/// proposition or edge — collected rather than silently dropped, so the
+/// caller can show the user what didn't make it into the graph.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconstructionResult {
+    pub graph: LogicalGraph,
+    pub warnings: Vec<String>,
+}
+
+/// Resource limits `pipeline::AnalysisContext` applies to the centrality and
+/// cycle-detection stages on large graphs, so neither one can hang a request
+/// on a pathologically big or densely-connected graph. `centrality_sample_size`
+/// source nodes are used for Brandes' algorithm instead of every node once the
+/// graph exceeds `centrality_sampling_threshold` propositions (see
+/// `graph::centrality::betweenness_centrality_bounded`); cycle enumeration
+/// stops and reports "more exist" once it would emit more than `max_cycles`
+/// cycles or take more than `max_cycle_search_visits` circuit-search steps
+/// (see `graph::cycle_detection::enumerate_elementary_cycles_bounded`). The
+/// values actually applied are echoed back on `AnalysisResult::search_budget`
+/// so a caller can see, after the fact, whether a result could have been cut
+/// short and by what limits.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchBudget {
+    pub centrality_sampling_threshold: usize,
+    pub centrality_sample_size: usize,
+    pub max_cycles: usize,
+    pub max_cycle_search_visits: usize,
+}
+
+impl Default for SearchBudget {
+    fn default() -> Self {
+        Self {
+            centrality_sampling_threshold: 500,
+            centrality_sample_size: 100,
+            max_cycles: 500,
+            max_cycle_search_visits: 200_000,
+        }
+    }
+}
+
+/// Whether `AnalysisResult::argument_scores` (via its centrality input) or
+/// `AnalysisResult::cycles` were cut short by `AnalysisResult::search_budget`
+/// rather than run to completion — `true` means that field is an estimate
+/// (centrality) or a truncated-but-honest prefix with more cycles left
+/// unreported (cycles), not the exact/exhaustive result.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisOverflow {
+    pub centrality_approximate: bool,
+    pub cycles_overflowed: bool,
+}
+
+/// The result of `pipeline::analyze_incremental`: the same `AnalysisResult`
+/// `analyze` would produce, plus the name of every stage that was served
+/// from `pipeline`'s content-hash cache instead of recomputed — evidence of
+/// how much the incremental call actually saved, and a way for a caller to
+/// confirm it (e.g. re-analyzing an unchanged graph should reuse every
+/// stage).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IncrementalAnalysisResult {
+    pub result: AnalysisResult,
+    pub reused_stages: Vec<String>,
 }
 
 // --- Master Result ---
@@ -159,6 +399,29 @@ pub struct AnalysisResult {
     pub argument_scores: Vec<ArgumentScore>,
     pub cycles: Vec<Vec<String>>,
     pub topological_order: Vec<String>,
+    /// The same dependency ordering as `topological_order`, but with cyclic
+    /// sub-arguments collapsed into a single cluster entry instead of
+    /// dropped — so a circular sub-argument doesn't erase whole branches
+    /// from the reading order. A singleton entry is an ordinary acyclic
+    /// proposition; a multi-member entry is a cyclic cluster. See
+    /// `graph::topo_sort::topological_sort_condensed`.
+    pub topological_order_condensed: Vec<Vec<String>>,
+    pub argument_camps: Vec<Community>,
+    pub equivocations: Vec<Equivocation>,
+    pub questions: Vec<Question>,
+    pub retraction_suggestions: Vec<RetractionSuggestion>,
+    /// The single smallest set of propositions whose retraction clears every
+    /// contradiction in `contradictions` at once — unlike
+    /// `retraction_suggestions`, which proposes one candidate per individual
+    /// contradiction, this spans all of them jointly, so a proposition
+    /// implicated in several contradictions only needs to appear once. See
+    /// `sat_solver::minimal_contradiction_core`.
+    pub minimal_retraction_core: Vec<String>,
+    pub truth_labels: Vec<PropositionStatus>,
+    pub grounding_gaps: Vec<GroundingGap>,
+    pub aba_extensions: AbaExtensions,
+    pub search_budget: SearchBudget,
+    pub overflow: AnalysisOverflow,
 }
 
 // --- Test helpers ---
@@ -177,6 +440,7 @@ pub mod test_helpers {
             is_implicit: false,
             is_load_bearing: prop_type == "claim",
             is_anchored: false,
+            source: None,
         }
     }
 